@@ -0,0 +1,69 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use graphics::Context;
+
+use rusty_platformer::config;
+use rusty_platformer::map::{Map, TileType, Tileset, TileLayer, LayerKind};
+use rusty_platformer::map_renderer::TileBatcher;
+
+// Times building the per-tileset vertex batches on the CPU, not drawing them. `Map` stores its
+// size as `i8`, so 120x120 tiles is the largest map the collider areas still fit.
+const SIZE: usize = 120;
+const TILE_SIZE: f64 = 24.0;
+
+fn largest_map() -> Map {
+    let tiles = (0..SIZE)
+        .map(|row| (0..SIZE).map(|column| match (row * 7 + column * 13) % 5 {
+            0 => TileType::Block,
+            1 => TileType::OneWay,
+            _ => TileType::Empty
+        }).collect())
+        .collect();
+
+    let mut map = Map::new(tiles, [0.0, 0.0], SIZE as i8, SIZE as i8, TILE_SIZE);
+
+    let tilesets = vec![Tileset {
+        image: "Tiles/tileset.png".to_string(),
+        first_gid: 1,
+        tile_width: Some(16.0),
+        tile_height: Some(16.0),
+        margin: 0.0,
+        spacing: 0.0
+    }];
+
+    let layers = [LayerKind::Background, LayerKind::Collision, LayerKind::Foreground]
+        .iter()
+        .map(|kind| TileLayer {
+            name: String::new(),
+            kind: *kind,
+            tiles: (0..SIZE).map(|row| (0..SIZE).map(|column| ((row + column) % 64) as u32).collect()).collect()
+        })
+        .collect();
+
+    map.set_layers(tilesets, layers);
+    map
+}
+
+fn build_batches(c: &mut Criterion) {
+    let map = largest_map();
+    let texture_sizes = [(128, 128)];
+    let transform = Context::new_abs(config::BOARD_SIZE_X as f64, config::BOARD_SIZE_Y as f64).transform;
+    let mut batcher = TileBatcher::new(map.tilesets.len());
+
+    let screen = [0.0, 0.0, config::BOARD_SIZE_X as f64, config::BOARD_SIZE_Y as f64];
+    let whole_map = [0.0, 0.0, SIZE as f64 * TILE_SIZE, SIZE as f64 * TILE_SIZE];
+
+    c.bench_function("batch layers, culled to screen", |b| b.iter(|| {
+        for layer in map.layers.iter() {
+            batcher.build(&map, layer, screen, &texture_sizes, transform);
+        }
+    }));
+
+    c.bench_function("batch layers, whole map", |b| b.iter(|| {
+        for layer in map.layers.iter() {
+            batcher.build(&map, layer, whole_map, &texture_sizes, transform);
+        }
+    }));
+}
+
+criterion_group!(benches, build_batches);
+criterion_main!(benches);
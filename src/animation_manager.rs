@@ -3,9 +3,9 @@ use std::collections::HashMap;
 use std::rc::Rc;
 use std::cell::RefCell;
 use std::cell::RefMut;
-use opengl_graphics::Texture;
 use super::texture_loader::TextureLoader;
-use super::animator::Animator;
+use super::animator::{Animator, Frame};
+use super::sprite_atlas::SpriteAtlas;
 
 pub struct AnimationManager {
     animators: HashMap<String, RefCell<Animator>>,
@@ -21,15 +21,33 @@ impl AnimationManager {
     }
 
     pub fn add_sequence(&mut self, name: String, file_name: &str, interval: f64, start: i8, stop: i8, box_size: Vec2d) {
-        let mut textures = Vec::<Texture>::new();
+        let mut textures = Vec::<Frame>::new();
         for i in start..stop + 1 {
             let texture = self.tex_loader.load_texture(&format!("{} ({}).png", file_name, i));
-            textures.push(texture);
+            textures.push(Frame::whole(texture));
         }
         let animator = RefCell::new(Animator::new(textures, interval, box_size));
         self.animators.insert(name, animator);
     }
 
+    pub fn add_atlas_sequence(&mut self, name: String, atlas: &SpriteAtlas, sequence: &str, interval: f64, box_size: Vec2d) {
+        let atlas_frames = atlas.sequence(sequence);
+        if atlas_frames.is_empty() {
+            panic!("atlas has no frames for sequence {}", sequence);
+        }
+
+        let frames = atlas_frames.iter().map(|frame| atlas.frame(frame)).collect();
+        let durations: Vec<f64> = atlas_frames.iter().map(|frame| frame.duration.unwrap_or(interval)).collect();
+
+        let mut animator = Animator::new(frames, interval, box_size);
+        animator.set_frame_durations(&durations);
+        self.animators.insert(name, RefCell::new(animator));
+    }
+
+    pub fn add_atlas(&mut self, descriptor_path: &str) -> SpriteAtlas {
+        SpriteAtlas::load(&self.tex_loader, descriptor_path)
+    }
+
     pub fn get_animator(&self, name: String) -> RefMut<Animator> {
         self.animators[&name].borrow_mut()
     }
@@ -1,11 +1,42 @@
-use opengl_graphics::{Texture, GlGraphics};
-use graphics::Context;
+use opengl_graphics::GlGraphics;
+use graphics::{Context, ImageSize};
 use graphics::math::*;
+use super::colors;
+use super::texture_loader::TextureHandle;
+
+const MIN_FRAME_DURATION: f64 = 0.001;
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum PlaybackMode {
+    Loop,
+    Once,
+    PingPong
+}
+
+pub struct Frame {
+    pub texture: TextureHandle,
+    pub source: [f64; 4]
+}
+
+impl Frame {
+    pub fn whole(texture: TextureHandle) -> Frame {
+        let (width, height) = texture.borrow().get_size();
+        Frame {
+            texture,
+            source: [0.0, 0.0, width as f64, height as f64]
+        }
+    }
+}
 
 pub struct Animator {
-    sprites: Vec<Texture>,
-    interval: f64,
+    sprites: Vec<Frame>,
+    durations: Vec<f64>,
+    mode: PlaybackMode,
+    frame_events: Vec<(usize, String)>,
+    on_complete: Option<Box<dyn FnMut()>>,
     current_sprite: usize,
+    backwards: bool,
+    finished: bool,
     time_since_last: f64,
     scale_x: f64,
     scale_y: f64, 
@@ -15,21 +46,26 @@ pub struct Animator {
 }
 
 impl Animator {
-    pub fn new(sprites: Vec<Texture>, interval: f64, box_size: Vec2d) -> Animator {
-        use graphics::*;
-        
-        let sprite_size = sprites[0].get_size();
+    pub fn new(sprites: Vec<Frame>, interval: f64, box_size: Vec2d) -> Animator {
+        let sprite_size = (sprites[0].source[2], sprites[0].source[3]);
 
-        let scale_x = box_size[0] / sprite_size.0 as f64;
-        let scale_y = box_size[1] / sprite_size.1 as f64;
+        let scale_x = box_size[0] / sprite_size.0;
+        let scale_y = box_size[1] / sprite_size.1;
 
-        let sprite_half_size_x = (sprite_size.0 / 2) as f64  * scale_x;
+        let sprite_half_size_x = (sprite_size.0 / 2.0).floor() * scale_x;
         let sprite_size_x = sprite_half_size_x * 2.0;
 
+        let durations = vec![interval.max(MIN_FRAME_DURATION); sprites.len()];
+
         Animator {
             sprites,
-            interval,
+            durations,
+            mode: PlaybackMode::Loop,
+            frame_events: Vec::new(),
+            on_complete: None,
             current_sprite: 0,
+            backwards: false,
+            finished: false,
             time_since_last: 0.0,
             scale_x,
             scale_y,
@@ -39,23 +75,113 @@ impl Animator {
         }
     }
 
-    pub fn next(&mut self, delta: f64) {
+    pub fn set_mode(&mut self, mode: PlaybackMode) {
+        self.mode = mode;
+    }
+
+    pub fn set_frame_durations(&mut self, durations: &[f64]) {
+        for (i, duration) in durations.iter().enumerate().take(self.durations.len()) {
+            self.durations[i] = duration.max(MIN_FRAME_DURATION);
+        }
+    }
+
+    pub fn add_frame_event(&mut self, frame: usize, name: &str) {
+        self.frame_events.push((frame, name.to_string()));
+    }
+
+    // Called once each time a clip played with `PlaybackMode::Once` reaches its last frame.
+    pub fn set_on_complete(&mut self, callback: Box<dyn FnMut()>) {
+        self.on_complete = Some(callback);
+    }
+
+    pub fn restart(&mut self) {
+        self.current_sprite = 0;
+        self.backwards = false;
+        self.finished = false;
+        self.time_since_last = 0.0;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    pub fn next(&mut self, delta: f64) -> Vec<String> {
+        let mut events = Vec::new();
+        if self.finished {
+            return events;
+        }
+
         self.time_since_last += delta;
-        if self.time_since_last >= self.interval {
-            self.time_since_last = 0.0;
-            if self.current_sprite == self.sprites.len() - 1{
-                self.current_sprite = 0;
+        while !self.finished && self.time_since_last >= self.durations[self.current_sprite] {
+            self.time_since_last -= self.durations[self.current_sprite];
+            self.advance();
+
+            if !self.finished {
+                events.extend(self.frame_events
+                    .iter()
+                    .filter(|(frame, _)| *frame == self.current_sprite)
+                    .map(|(_, name)| name.clone()));
+            }
+        }
+
+        events
+    }
+
+    fn advance(&mut self) {
+        let last = self.sprites.len() - 1;
+
+        match self.mode {
+            PlaybackMode::Loop => {
+                self.current_sprite = if self.current_sprite == last { 0 } else { self.current_sprite + 1 };
+            }
+            PlaybackMode::Once => {
+                if self.current_sprite == last {
+                    self.complete();
+                } else {
+                    self.current_sprite += 1;
+                }
             }
-            else {
-                self.current_sprite += 1;
+            PlaybackMode::PingPong => {
+                if last == 0 {
+                    return;
+                }
+                if self.current_sprite == last {
+                    self.backwards = true;
+                } else if self.current_sprite == 0 {
+                    self.backwards = false;
+                }
+
+                if self.backwards {
+                    self.current_sprite -= 1;
+                } else {
+                    self.current_sprite += 1;
+                }
             }
         }
     }
 
+    fn complete(&mut self) {
+        self.finished = true;
+        self.time_since_last = 0.0;
+
+        if let Some(callback) = self.on_complete.as_mut() {
+            callback();
+        }
+    }
+
+    pub fn current_frame(&self) -> usize {
+        self.current_sprite
+    }
+
     pub fn render(&mut self, ctx: &Context, gl: &mut GlGraphics, position: Vec2d, mirror: bool){
+        self.render_frame(ctx, gl, self.current_sprite, position, mirror, colors::WHITE);
+    }
+
+    pub fn render_frame(&self, ctx: &Context, gl: &mut GlGraphics, frame: usize, position: Vec2d, mirror: bool, color: [f32; 4]){
         use graphics::*;
 
-        let sprite = &self.sprites[self.current_sprite];
+        let sprite = &self.sprites[frame.min(self.sprites.len() - 1)];
+        let image = Image::new_color(color).src_rect(sprite.source);
         
         let position_y = position[1];
         let position_x = position[0] - self.sprite_half_size_x + (self.box_size[0] / 2.0);
@@ -70,10 +196,58 @@ impl Animator {
                 .scale(self.scale_x, self.scale_y);
             
             flipped_transform = flipped_transform.flip_h();
-            image(sprite, flipped_transform, gl);
+            if let Some(texture) = sprite.texture.borrow().gl() {
+                image.draw(texture, &ctx.draw_state, flipped_transform, gl);
+            }
             return;
         }
         
-        image(sprite, transform, gl);
+        if let Some(texture) = sprite.texture.borrow().gl() {
+            image.draw(texture, &ctx.draw_state, transform, gl);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::texture_loader::LoadedTexture;
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    fn animator(count: usize, interval: f64) -> Animator {
+        let texture = Rc::new(RefCell::new(LoadedTexture::Headless(10, 10)));
+        let sprites = (0..count).map(|_| Frame::whole(Rc::clone(&texture))).collect();
+        Animator::new(sprites, interval, [10.0, 10.0])
+    }
+
+    #[test]
+    fn once_calls_on_complete_when_it_finishes() {
+        let completed = Rc::new(Cell::new(0));
+        let mut animator = animator(3, 0.1);
+        animator.set_mode(PlaybackMode::Once);
+        let counter = Rc::clone(&completed);
+        animator.set_on_complete(Box::new(move || counter.set(counter.get() + 1)));
+
+        animator.next(0.25);
+        assert_eq!(completed.get(), 0);
+        assert!(!animator.is_finished());
+
+        animator.next(1.0);
+        animator.next(1.0);
+        assert_eq!(completed.get(), 1);
+        assert!(animator.is_finished());
+        assert_eq!(animator.current_frame(), 2);
+
+        animator.restart();
+        animator.next(0.35);
+        assert_eq!(completed.get(), 2);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn zero_intervals_still_advance() {
+        let mut animator = animator(4, 0.0);
+        animator.next(0.0025);
+        assert_eq!(animator.current_frame(), 2);
+    }
+}
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::SystemTime;
+
+use super::config;
+
+pub struct AssetWatcher {
+    assets_path: Rc<PathBuf>,
+    modified: HashMap<String, SystemTime>,
+    time_since_poll: f64
+}
+
+impl AssetWatcher {
+    pub fn new(assets_path: Rc<PathBuf>) -> AssetWatcher {
+        let mut watcher = AssetWatcher {
+            assets_path,
+            modified: HashMap::new(),
+            time_since_poll: 0.0
+        };
+        watcher.scan();
+        watcher
+    }
+
+    // Returns asset paths, relative to the assets folder, that changed since the last poll.
+    pub fn poll(&mut self, delta: f64) -> Vec<String> {
+        self.time_since_poll += delta;
+        if self.time_since_poll < config::ASSET_POLL_INTERVAL {
+            return Vec::new();
+        }
+        self.time_since_poll = 0.0;
+        self.scan()
+    }
+
+    fn scan(&mut self) -> Vec<String> {
+        let mut files = Vec::new();
+        collect_files(&self.assets_path, &mut files);
+
+        let mut changed = Vec::new();
+        for file in files {
+            let modified = match fs::metadata(&file).and_then(|metadata| metadata.modified()) {
+                Ok(modified) => modified,
+                Err(_) => continue
+            };
+
+            let relative = file
+                .strip_prefix(self.assets_path.as_path())
+                .unwrap()
+                .to_string_lossy()
+                .replace('\\', "/");
+
+            if let Some(previous) = self.modified.insert(relative.clone(), modified) {
+                if previous != modified {
+                    changed.push(relative);
+                }
+            }
+        }
+        changed
+    }
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_files(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
@@ -1,49 +1,128 @@
-use opengl_graphics::{Texture, GlGraphics};
+use opengl_graphics::GlGraphics;
 use graphics::Context;
+use serde::Deserialize;
 use super::renderable::Renderable;
 use super::camera::CameraDependentObject;
 use super::config;
+use super::texture_loader::{TextureLoader, TextureHandle};
+
+#[derive(Copy, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RepeatMode {
+    None,
+    Horizontal,
+    Vertical,
+    Both
+}
+
+#[derive(Deserialize)]
+pub struct LayerConfig {
+    pub image: String,
+    // How far the layer scrolls compared to the camera, 1.0 moves with the level.
+    pub factor: [f64; 2],
+    #[serde(default)]
+    pub offset_y: f64,
+    pub repeat: RepeatMode
+}
+
+impl LayerConfig {
+    pub fn defaults() -> Vec<LayerConfig> {
+        vec![
+            LayerConfig {
+                image: "City Background.png".to_string(),
+                factor: [config::BACKGROUND_PARRALAX_FACTOR, 0.0],
+                offset_y: 0.0,
+                repeat: RepeatMode::Horizontal
+            },
+            LayerConfig {
+                image: "City Foreground.png".to_string(),
+                factor: [1.0, 0.0],
+                offset_y: 0.0,
+                repeat: RepeatMode::Horizontal
+            }
+        ]
+    }
+}
+
+struct ParallaxLayer {
+    texture: TextureHandle,
+    factor: [f64; 2],
+    offset_y: f64,
+    repeat: RepeatMode
+}
 
 pub struct Background {
-    background_texture: Texture,
-    foreground_texture: Texture,
+    layers: Vec<ParallaxLayer>,
     pub x: f64,
-    y: f64,
-    repeat: i8,
-    width: f64,
-    pub left: bool,
-    pub right: bool,
-    pub combined_width: f64
+    y: f64
 }
 
 impl Background {
-    pub fn new(background_texture: Texture, foreground_texture: Texture, repeat: i8, width: f64) -> Background {
-        Background {
-            background_texture,
-            foreground_texture,
+    pub fn new(texture_loader: &TextureLoader, layers: &[LayerConfig]) -> Background {
+        let mut background = Background {
+            layers: Vec::new(),
             x: 0.0,
-            y: 0.0,
-            width,
-            left: false,
-            right: false,
-            repeat,
-            combined_width: width * repeat as f64
-        }
+            y: 0.0
+        };
+        background.set_layers(texture_loader, layers);
+        background
+    }
+
+    // Keeps the scroll position, so layers can be swapped while the level runs.
+    pub fn set_layers(&mut self, texture_loader: &TextureLoader, layers: &[LayerConfig]) {
+        self.layers = layers
+            .iter()
+            .map(|layer| ParallaxLayer {
+                texture: texture_loader.load_texture(&layer.image),
+                factor: layer.factor,
+                offset_y: layer.offset_y,
+                repeat: layer.repeat
+            })
+            .collect();
     }
 }
 
+// Positions of the copies needed to cover `0..view` when one copy starts at `start`.
+fn copies(start: f64, size: f64, view: f64, repeat: bool) -> Vec<f64> {
+    if !repeat || size <= 0.0 {
+        return vec![start];
+    }
+
+    let mut first = start.rem_euclid(size);
+    if first > 0.0 {
+        first -= size;
+    }
+    (0..)
+        .map(|i| first + size * i as f64)
+        .take_while(|position| *position < view)
+        .collect()
+}
+
 impl Renderable for Background {
     fn render(&mut self, ctx: &Context, gl: &mut GlGraphics) {
         use graphics::*;
-        
-        for i in -1..self.repeat + 1 {
-            let transform = ctx.transform.trans(self.x * config::BACKGROUND_PARRALAX_FACTOR + (self.width * i as f64), self.y);
-            image(&self.background_texture, transform, gl);    
-        }
 
-        for i in -1..self.repeat + 1 {
-            let transform = ctx.transform.trans(self.x + (self.width * i as f64), self.y);
-            image(&self.foreground_texture, transform, gl);    
+        let view = [config::BOARD_SIZE_X as f64, config::BOARD_SIZE_Y as f64];
+
+        for layer in self.layers.iter() {
+            let texture = layer.texture.borrow();
+            let (width, height) = texture.get_size();
+            let texture = match texture.gl() {
+                Some(texture) => texture,
+                None => continue
+            };
+
+            let x = self.x * layer.factor[0];
+            let y = self.y * layer.factor[1] + layer.offset_y;
+
+            let repeat_x = layer.repeat == RepeatMode::Horizontal || layer.repeat == RepeatMode::Both;
+            let repeat_y = layer.repeat == RepeatMode::Vertical || layer.repeat == RepeatMode::Both;
+
+            for copy_y in copies(y, height as f64, view[1], repeat_y) {
+                for copy_x in copies(x, width as f64, view[0], repeat_x) {
+                    image(texture, ctx.transform.trans(copy_x, copy_y), gl);
+                }
+            }
         }
     }
 }
@@ -53,4 +132,4 @@ impl CameraDependentObject for Background {
         self.x += x;
         self.y += y;
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,183 @@
+use rusty_platformer::config;
+use rusty_platformer::map::Map;
+use rusty_platformer::map_loader::{Level, MapLoader, ObjectKind};
+use rusty_platformer::moving_object::MovingObject;
+use rusty_platformer::nav_graph::NavGraph;
+use rusty_platformer::pickup::Pickup;
+use rusty_platformer::trigger::{Trigger, TriggerAction};
+use std::path::PathBuf;
+use std::rc::Rc;
+
+static USAGE: &str = "Usage: reachability [--assets <dir>] [--annotate <file>] <level>
+
+Works out where the player can get to from the spawn with the game's jump physics,
+lists platforms nobody can stand on and exits with status 1 if a pickup, checkpoint
+or exit can't be reached.
+
+Options:
+    --assets <dir>       assets directory instead of searching for one
+    --annotate <file>    write the map with '+' above reachable and '-' above unreachable ground
+    --help               print this message";
+
+fn main() {
+    let mut assets = None;
+    let mut annotate = None;
+    let mut level_path = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--help" => {
+                println!("{}", USAGE);
+                return;
+            },
+            "--assets" => assets = Some(PathBuf::from(args.next().unwrap_or_else(|| usage_error("--assets needs a directory")))),
+            "--annotate" => annotate = Some(PathBuf::from(args.next().unwrap_or_else(|| usage_error("--annotate needs a file")))),
+            option if option.starts_with("--") => usage_error(&format!("unknown option {}", option)),
+            _ if level_path.is_some() => usage_error("only one level can be checked at a time"),
+            _ => level_path = Some(arg)
+        }
+    }
+
+    let level_path = level_path.unwrap_or_else(|| usage_error("no level given"));
+    let assets = assets.unwrap_or_else(|| find_folder::Search::ParentsThenKids(3, 3)
+        .for_folder("assets")
+        .unwrap_or_else(|e| {
+            eprintln!("can't find the assets directory: {}", e);
+            std::process::exit(2);
+        }));
+
+    let tile_size = config::TILE_SIZE as f64;
+    let mut level = MapLoader::new(Rc::new(assets))
+        .load_level(&level_path, tile_size)
+        .unwrap_or_else(|e| {
+            eprintln!("can't load level {}: {}", level_path, e);
+            std::process::exit(2);
+        });
+    level.add_default_objects(tile_size);
+
+    let spawn = level.objects_of(ObjectKind::Spawn).next().unwrap().position;
+    let player = MovingObject::new(
+        spawn,
+        config::CHARACTER_SIZE,
+        [0.0, 1080.0],
+        config::ACCELERATION,
+        config::WALK_SPEED,
+        config::JUMP_SPEED,
+        "player".to_string());
+
+    let (width, height) = level.size().unwrap_or_else(|e| {
+        eprintln!("can't load level {}: {}", level_path, e);
+        std::process::exit(2);
+    });
+    let map = Map::new(level.tiles.clone(), [0.0, 0.0], width, height, tile_size);
+    let graph = NavGraph::new(&map, player.movement());
+
+    let start = graph.landing(&map, spawn).unwrap_or_else(|| {
+        println!("{}: the player spawn at [{}, {}] never lands anywhere", level_path, spawn[0], spawn[1]);
+        std::process::exit(1);
+    });
+    let reachable = graph.reachable_from(start);
+
+    report_platforms(&level_path, &level, &graph, &reachable);
+    let unreachable = report_objects(&level_path, &level, &map, &graph, &reachable);
+
+    if let Some(annotate) = annotate {
+        if let Err(error) = std::fs::write(&annotate, annotated_map(&level, &graph, &reachable)) {
+            eprintln!("can't write {}: {}", annotate.display(), error);
+            std::process::exit(2);
+        }
+    }
+
+    if unreachable > 0 {
+        std::process::exit(1);
+    }
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{}\n\n{}", message, USAGE);
+    std::process::exit(2);
+}
+
+// Platforms are runs of standing places next to each other on the same row.
+fn report_platforms(path: &str, level: &Level, graph: &NavGraph, reachable: &[bool]) {
+    let mut platforms: Vec<(i32, i32, i32, bool)> = Vec::new();
+    for (node, reachable) in graph.nodes.iter().zip(reachable.iter()) {
+        match platforms.last_mut() {
+            Some(platform) if platform.0 == node.row && platform.2 + 1 == node.column => {
+                platform.2 = node.column;
+                platform.3 |= *reachable;
+            },
+            _ => platforms.push((node.row, node.column, node.column, *reachable))
+        }
+    }
+
+    let reachable_count = platforms.iter().filter(|platform| platform.3).count();
+    println!("{}: {} of {} platforms are reachable from the spawn", path, reachable_count, platforms.len());
+
+    for (row, first, last, _) in platforms.iter().filter(|platform| !platform.3) {
+        let ground = if *row as usize >= level.height() {
+            "the bottom of the level".to_string()
+        } else {
+            format!("line {}", row + 1)
+        };
+        println!("{}: platform on {}, columns {}-{} is unreachable", path, ground, first + 1, last + 1);
+    }
+}
+
+fn report_objects(path: &str, level: &Level, map: &Map, graph: &NavGraph, reachable: &[bool]) -> usize {
+    let mut pickups: Vec<(Pickup, bool)> = level.objects_of(ObjectKind::Pickup).map(|object| (Pickup::new(object), false)).collect();
+    let mut triggers: Vec<(Trigger, bool)> = level.objects_of(ObjectKind::Trigger)
+        .filter_map(Trigger::from_object)
+        .map(|trigger| (trigger, false))
+        .collect();
+
+    for node in (0..graph.nodes.len()).filter(|node| reachable[*node]) {
+        graph.sweep(map, node, &mut |area| {
+            for (pickup, reached) in pickups.iter_mut().filter(|(_, reached)| !*reached) {
+                *reached = pickup.overlaps(area);
+            }
+            for (trigger, reached) in triggers.iter_mut().filter(|(_, reached)| !*reached) {
+                *reached = trigger.overlaps(area);
+            }
+        });
+    }
+
+    let mut unreachable = 0;
+    for (pickup, _) in pickups.iter().filter(|(_, reached)| !*reached) {
+        println!("{}: pickup at [{}, {}] can't be reached", path, pickup.position[0], pickup.position[1]);
+        unreachable += 1;
+    }
+    for (trigger, _) in triggers.iter().filter(|(_, reached)| !*reached) {
+        let name = match trigger.action {
+            TriggerAction::Checkpoint => "checkpoint",
+            TriggerAction::Exit => "exit"
+        };
+        println!("{}: {} at [{}, {}] can't be reached", path, name, trigger.area[0], trigger.area[1]);
+        unreachable += 1;
+    }
+    unreachable
+}
+
+fn annotated_map(level: &Level, graph: &NavGraph, reachable: &[bool]) -> String {
+    let mut rows: Vec<Vec<char>> = level.tiles
+        .iter()
+        .map(|row| row.iter().map(|tile| tile.to_char()).collect())
+        .collect();
+
+    for (node, reachable) in graph.nodes.iter().zip(reachable.iter()) {
+        let (x, y) = (node.column as usize, node.row as usize);
+        if y == 0 || y > rows.len() {
+            continue;
+        }
+
+        let mark = &mut rows[y - 1][x];
+        if *reachable {
+            *mark = '+';
+        } else if *mark != '+' {
+            *mark = '-';
+        }
+    }
+
+    rows.into_iter().map(|row| row.into_iter().chain(std::iter::once('\n')).collect::<String>()).collect()
+}
@@ -0,0 +1,164 @@
+use rusty_platformer::collider;
+use rusty_platformer::config;
+use rusty_platformer::map::TileType;
+use rusty_platformer::map_loader::{Level, LevelObject, MapLoader, ObjectKind};
+use std::path::PathBuf;
+use std::rc::Rc;
+
+static USAGE: &str = "Usage: validate_map [--assets <dir>] <level>...
+
+Checks levels for problems that would otherwise only show up in the game
+and exits with status 1 if any level has one.
+
+Options:
+    --assets <dir>    assets directory instead of searching for one
+    --help            print this message";
+
+fn main() {
+    let mut assets = None;
+    let mut levels = Vec::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--help" => {
+                println!("{}", USAGE);
+                return;
+            },
+            "--assets" => match args.next() {
+                Some(dir) => assets = Some(PathBuf::from(dir)),
+                None => usage_error("--assets needs a directory")
+            },
+            option if option.starts_with("--") => usage_error(&format!("unknown option {}", option)),
+            _ => levels.push(arg)
+        }
+    }
+
+    if levels.is_empty() {
+        usage_error("no levels given");
+    }
+
+    let assets = match assets {
+        Some(assets) => assets,
+        None => find_folder::Search::ParentsThenKids(3, 3)
+            .for_folder("assets")
+            .unwrap_or_else(|e| {
+                eprintln!("can't find the assets directory: {}", e);
+                std::process::exit(2);
+            })
+    };
+    let map_loader = MapLoader::new(Rc::new(assets));
+
+    let mut failed = 0;
+    for level in levels.iter() {
+        let problems = validate(&map_loader, level);
+        if problems.is_empty() {
+            println!("{}: ok", level);
+        } else {
+            failed += 1;
+            for problem in problems.iter() {
+                println!("{}", problem);
+            }
+        }
+    }
+
+    if failed > 0 {
+        println!("{} of {} levels have problems", failed, levels.len());
+        std::process::exit(1);
+    }
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{}\n\n{}", message, USAGE);
+    std::process::exit(2);
+}
+
+fn validate(map_loader: &MapLoader, path: &str) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    // Tiled maps are checked by Tiled, only plain maps are read character by character.
+    if MapLoader::is_editable(path) {
+        match map_loader.read_map(path) {
+            Ok(content) => problems.extend(check_rows(path, &content)),
+            Err(error) => return vec![error]
+        }
+    }
+
+    let tile_size = config::TILE_SIZE as f64;
+    let mut level = match map_loader.load_level(path, tile_size) {
+        Ok(level) => level,
+        Err(error) => {
+            problems.push(format!("{}: {}", path, error));
+            return problems;
+        }
+    };
+    level.add_default_objects(tile_size);
+
+    if let Err(error) = map_loader.load_background(path) {
+        problems.push(format!("{}: {}", path, error));
+    }
+
+    problems.extend(check_size(path, "width", level.width()));
+    problems.extend(check_size(path, "height", level.height()));
+
+    let spawns = level.objects_of(ObjectKind::Spawn).take(1).map(|spawn| ("player spawn", spawn));
+    let enemies = level.objects_of(ObjectKind::Enemy).map(|enemy| ("enemy spawn", enemy));
+    for (name, object) in spawns.chain(enemies) {
+        if let Some(problem) = check_spawn(&level, object, tile_size) {
+            problems.push(format!("{}: {} at [{}, {}] {}", path, name, object.position[0], object.position[1], problem));
+        }
+    }
+
+    problems
+}
+
+fn check_rows(path: &str, content: &str) -> Vec<String> {
+    let mut problems = Vec::new();
+    let expected = content.lines().next().map_or(0, |line| line.chars().count());
+
+    for (y, line) in content.lines().enumerate() {
+        let length = line.chars().count();
+        if length != expected {
+            problems.push(format!("{}:{}: row has {} tiles, expected {} like line 1", path, y + 1, length, expected));
+        }
+
+        for (x, c) in line.chars().enumerate() {
+            if TileType::from_char(c).is_none() {
+                problems.push(format!("{}:{}:{}: unknown tile {:?}", path, y + 1, x + 1, c));
+            }
+        }
+    }
+
+    problems
+}
+
+fn check_size(path: &str, dimension: &str, tiles: usize) -> Vec<String> {
+    let area = config::COLLIDER_AREA_SIZE as usize;
+
+    if tiles == 0 {
+        vec![format!("{}: level has a {} of 0 tiles", path, dimension)]
+    } else if collider::padded_size(tiles).is_none() {
+        let largest = i8::MAX as usize / area * area;
+        vec![format!("{}: {} of {} tiles is more than the collider supports ({} tiles)", path, dimension, tiles, largest)]
+    } else {
+        Vec::new()
+    }
+}
+
+// Spawn positions are the top left corner of the character. Collisions push it out of blocks
+// it only overlaps, but it can't start outside the level or with its center inside a block.
+fn check_spawn(level: &Level, object: &LevelObject, tile_size: f64) -> Option<String> {
+    let x = object.position[0] + config::CHARACTER_SIZE[0] / 2.0;
+    let y = object.position[1] + config::CHARACTER_SIZE[1] / 2.0;
+    if x < 0.0 || y < 0.0 || x >= level.width() as f64 * tile_size || y >= level.height() as f64 * tile_size {
+        return Some("is outside the level".to_string());
+    }
+
+    let column = (x / tile_size) as usize;
+    let row = (y / tile_size) as usize;
+    if level.tiles[row].get(column).is_some_and(|tile| tile.is_solid()) {
+        return Some(format!("is inside a block at line {}, column {}", row + 1, column + 1));
+    }
+
+    None
+}
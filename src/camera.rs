@@ -2,6 +2,7 @@ use super::character::Character;
 use super::background::Background;
 use super::map::Map;
 use super::moving_object::MovingObject;
+use super::config;
 use std::rc::Rc;
 use std::cell::RefCell;
 use std::collections::HashMap;
@@ -19,53 +20,52 @@ impl Camera{
         }
     }
 
-    pub fn update(&mut self, objects: &mut HashMap<String, MovingObject>, map: &mut Map, _character: &mut Character, background: &mut Background, delta: f64) {
-        let character_object = &mut objects.get("1ad31e1d-494a-41fe-bb9c-e7b8b83e59f1").unwrap();
-        let position_x = character_object.position[0];
-        
-        if position_x <= self.min {
-            if background.x >= 0.0 {
-                return;
-            }
-            let mut character = &mut objects.get_mut("1ad31e1d-494a-41fe-bb9c-e7b8b83e59f1").unwrap();
+    // The part of the level on screen, in world coordinates.
+    pub fn visible_rect(&self, map: &Map) -> [f64; 4] {
+        [-map.position[0], -map.position[1], config::BOARD_SIZE_X as f64, config::BOARD_SIZE_Y as f64]
+    }
 
-            character.position[0] = self.min;
-            let move_x = delta * character.speed[0];
+    pub fn focus(&mut self, objects: &mut HashMap<String, MovingObject>, map: &mut Map, background: &mut Background, world_position: [f64; 2]) {
+        let screen_x = world_position[0].min(self.max);
+        let move_x = (screen_x - world_position[0] - map.position[0]) / config::MAP_TILES_PARRALAX_FACTOR;
 
-            if background.x - move_x >= 0.0 {
-                background.x = 0.0;
-                return;
-            }
+        self.scroll(objects, map, background, move_x);
 
-            background.move_object(-move_x, 0.0);
-            map.move_object(-move_x, 0.0);
-            
-            for (k, v) in objects.iter_mut() {
-                if k == "1ad31e1d-494a-41fe-bb9c-e7b8b83e59f1" {
-                    continue;
-                }
-                v.move_object(-move_x, 0.0);
-            }
-        }
+        let character = objects.get_mut("1ad31e1d-494a-41fe-bb9c-e7b8b83e59f1").unwrap();
+        character.position = [world_position[0] + map.position[0], world_position[1]];
+    }
 
-        if position_x >= self.max {
-            if background.x <= -(background.combined_width / 2.0) {
-                return;
-            }
-            let mut character = &mut objects.get_mut("1ad31e1d-494a-41fe-bb9c-e7b8b83e59f1").unwrap();
+    pub fn update(&mut self, objects: &mut HashMap<String, MovingObject>, map: &mut Map, _character: &mut Character, background: &mut Background, delta: f64) {
+        let character = objects.get_mut("1ad31e1d-494a-41fe-bb9c-e7b8b83e59f1").unwrap();
+        let position_x = character.position[0];
+        let move_x = delta * character.speed[0];
 
+        if position_x <= self.min && map.position[0] < 0.0 {
+            character.position[0] = self.min;
+            self.scroll(objects, map, background, -move_x);
+        } else if position_x >= self.max && map.position[0] > Camera::min_map_x(map) {
             character.position[0] = self.max;
-            let move_x = delta * character.speed[0];
+            self.scroll(objects, map, background, -move_x);
+        }
+    }
+
+    // The camera stops where the map ends, so the level size only depends on the map.
+    fn min_map_x(map: &Map) -> f64 {
+        -(map.size()[0] - config::BOARD_SIZE_X as f64).max(0.0)
+    }
+
+    fn scroll(&mut self, objects: &mut HashMap<String, MovingObject>, map: &mut Map, background: &mut Background, move_x: f64) {
+        let target = (map.position[0] + move_x * config::MAP_TILES_PARRALAX_FACTOR).clamp(Camera::min_map_x(map), 0.0);
+        let move_x = (target - map.position[0]) / config::MAP_TILES_PARRALAX_FACTOR;
 
-            background.move_object(-move_x, 0.0);
-            map.move_object(-move_x, 0.0);
+        background.move_object(move_x, 0.0);
+        map.move_object(move_x, 0.0);
 
-            for (k, v) in objects.iter_mut() {
-                if k == "1ad31e1d-494a-41fe-bb9c-e7b8b83e59f1" {
-                    continue;
-                }
-                v.move_object(-move_x, 0.0);
+        for (k, v) in objects.iter_mut() {
+            if k == "1ad31e1d-494a-41fe-bb9c-e7b8b83e59f1" {
+                continue;
             }
+            v.move_object(move_x, 0.0);
         }
     }
 }
@@ -8,10 +8,14 @@ use piston_window::rectangle;
 use graphics::*;
 use opengl_graphics::GlGraphics;
 use graphics::Context;
+use graphics::math::{Vec2d, add};
 use super::texture_loader::TextureLoader;
 use super::animation_manager::AnimationManager;
+use super::animator::PlaybackMode;
 use super::colors;
 use super::map::Map;
+use super::speedrun::GhostFrame;
+use super::AABB::AABB;
 
 pub struct Character {
     pub key_pressed_map: Rc<RefCell<HashMap<Key, bool>>>,
@@ -19,9 +23,17 @@ pub struct Character {
     pub pressed_right: bool,
     pub pressed_jump: bool,
     pub pressed_drop: bool,
+    pub pressed_up: bool,
+    pub pressed_attack: bool,
+    // Enemies already hit by the current swing, so each is only hit once.
+    pub attack_hits: Vec<String>,
+    attack_live: bool,
+    // Time after jumping off a ladder before a ladder can be grabbed again.
+    climb_cooldown: f64,
     current_state: CharacterState,
     current_animator: String,
     animation_manager: AnimationManager,
+    animation_events: Vec<String>,
     turned_back: bool,
     box_size_x: f64,
     box_size_y: f64
@@ -32,9 +44,33 @@ impl Character {
 
         let mut animation_manager = AnimationManager::new(tex_loader);
 
-        animation_manager.add_sequence("idle".to_string(), "Character/Idle", 0.1, 1, 10, [box_size_x, box_size_y]);
-        animation_manager.add_sequence("run".to_string(), "Character/Run", 0.1, 1, 8, [box_size_x, box_size_y]);
-        animation_manager.add_sequence("jump".to_string(), "Character/Jump", 0.1, 1, 10, [box_size_x, box_size_y]);
+        for name in ["idle", "run", "jump"].iter() {
+            let atlas = animation_manager.add_atlas(&format!("Character/{}.atlas.yaml", name));
+            animation_manager.add_atlas_sequence(name.to_string(), &atlas, name, 0.1, [box_size_x, box_size_y]);
+        }
+
+        animation_manager.get_animator("idle".to_string()).set_mode(PlaybackMode::PingPong);
+        animation_manager.get_animator("run".to_string()).add_frame_event(2, "footstep");
+        animation_manager.get_animator("run".to_string()).add_frame_event(6, "footstep");
+
+        let mut jump = animation_manager.get_animator("jump".to_string());
+        jump.set_mode(PlaybackMode::Once);
+        jump.set_frame_durations(&[0.05, 0.05, 0.08, 0.1, 0.12, 0.12, 0.1, 0.08, 0.08, 0.1]);
+        drop(jump);
+
+        let attack_atlas = animation_manager.add_atlas("Character/attack.atlas.yaml");
+        animation_manager.add_atlas_sequence("attack".to_string(), &attack_atlas, "attack", 0.06, [box_size_x, box_size_y]);
+        let mut attack = animation_manager.get_animator("attack".to_string());
+        attack.set_mode(PlaybackMode::Once);
+        attack.set_frame_durations(&[0.08, 0.05, 0.05, 0.05, 0.1]);
+        attack.add_frame_event(config::MELEE_HIT_FRAMES.0, "hitbox_on");
+        attack.add_frame_event(config::MELEE_HIT_FRAMES.1, "hitbox_off");
+        drop(attack);
+
+        // Climb frames only advance while moving on the ladder.
+        let climb_atlas = animation_manager.add_atlas("Character/climb.atlas.yaml");
+        animation_manager.add_atlas_sequence("climb".to_string(), &climb_atlas, "climb", 0.1, [box_size_x, box_size_y]);
+        animation_manager.get_animator("climb".to_string()).set_mode(PlaybackMode::PingPong);
 
         Character { 
             current_state: CharacterState::Stand,
@@ -43,8 +79,14 @@ impl Character {
             pressed_left: false,
             pressed_right: false,
             pressed_drop: false,
+            pressed_up: false,
+            pressed_attack: false,
+            attack_hits: Vec::new(),
+            attack_live: false,
+            climb_cooldown: 0.0,
             current_animator: "idle".to_string(),
             animation_manager: animation_manager,
+            animation_events: Vec::new(),
             turned_back: false,
             box_size_x: box_size_x,
             box_size_y: box_size_y
@@ -52,25 +94,75 @@ impl Character {
     }
 
     pub fn character_update(&mut self, delta: f64, map: &Map, moving_object: &mut MovingObject){
+        let previous_state = self.current_state;
+        self.climb_cooldown = (self.climb_cooldown - delta).max(0.0);
+
         match &self.current_state {
             CharacterState::Stand => {
-                self.handle_stand(delta, moving_object);
+                self.handle_stand(delta, map, moving_object);
             },
             CharacterState::Walk => {
-                self.handle_walk(delta, moving_object);
+                self.handle_walk(delta, map, moving_object);
             },
             CharacterState::Jump => {
-                self.handle_jump(delta, moving_object);
+                self.handle_jump(delta, map, moving_object);
+            },
+            CharacterState::Attack => {
+                self.handle_attack(delta, moving_object);
+            },
+            CharacterState::Climb => {
+                self.handle_climb(delta, map, moving_object);
             },
             CharacterState::GrabLedge => {}
         }
+        self.pressed_attack = false;
 
         moving_object.update_physics(delta, &map);
-        self.animation_manager.get_animator(self.current_animator.to_string()).next(delta);
+
+        self.current_animator = self.current_state.animation().to_string();
+        let mut animator = self.animation_manager.get_animator(self.current_animator.to_string());
+        if self.current_state != previous_state {
+            animator.restart();
+        }
+        let still_on_ladder = self.current_state == CharacterState::Climb && moving_object.speed[1] == 0.0;
+        self.animation_events = animator.next(if still_on_ladder { 0.0 } else { delta });
+        drop(animator);
+
+        // The hitbox only hurts on the frames between these events.
+        if self.animation_events.iter().any(|event| event == "hitbox_on") {
+            self.attack_live = true;
+        }
+        if self.animation_events.iter().any(|event| event == "hitbox_off") || self.current_state != CharacterState::Attack {
+            self.attack_live = false;
+        }
+    }
+
+    // Area hurt by the swing, in front of the character.
+    pub fn attack_hitbox(&self, moving_object: &MovingObject) -> Option<AABB> {
+        if !self.attack_live {
+            return None;
+        }
+
+        let center = add(moving_object.position, moving_object.aabb_offset);
+        let half_size = [config::MELEE_RANGE / 2.0, moving_object.aabb.half_size[1]];
+        let offset = (moving_object.aabb.half_size[0] + half_size[0]) * self.facing();
+        Some(AABB::new([center[0] + offset, center[1]], half_size))
+    }
+
+    // 1.0 when facing right, -1.0 when facing left.
+    pub fn facing(&self) -> f64 {
+        if self.turned_back { -1.0 } else { 1.0 }
+    }
+
+    pub fn animation_events(&self) -> &[String] {
+        &self.animation_events
     }
 
-    fn handle_stand(&mut self, _delta: f64, moving_object: &mut MovingObject) {
-        self.current_animator = "idle".to_string();
+    fn handle_stand(&mut self, _delta: f64, map: &Map, moving_object: &mut MovingObject) {
+        if self.start_climb(map, moving_object) {
+            return;
+        }
+
         if !moving_object.on_ground {
             self.current_state = CharacterState::Jump;
         }
@@ -79,7 +171,10 @@ impl Character {
             moving_object.drop();
         }
 
-        if self.pressed_left | self.pressed_right {
+        if self.pressed_attack {
+            self.start_attack(moving_object);
+        }
+        else if self.pressed_left | self.pressed_right {
             self.current_state = CharacterState::Walk;
         }
         else if self.pressed_jump {
@@ -91,8 +186,11 @@ impl Character {
         }
     }
 
-    fn handle_walk(&mut self, _delta: f64, moving_object: &mut MovingObject) {
-        self.current_animator = "run".to_string();
+    fn handle_walk(&mut self, _delta: f64, map: &Map, moving_object: &mut MovingObject) {
+        if self.start_climb(map, moving_object) {
+            return;
+        }
+
         if self.pressed_right {
             self.turned_back = false;
             if moving_object.pushes_right_wall {
@@ -115,11 +213,91 @@ impl Character {
         if self.pressed_jump {
             moving_object.jump();
             self.current_state = CharacterState::Jump;
+        } else if self.pressed_attack {
+            self.start_attack(moving_object);
+        }
+    }
+
+    fn start_attack(&mut self, moving_object: &mut MovingObject) {
+        moving_object.stop();
+        self.attack_hits.clear();
+        self.current_state = CharacterState::Attack;
+    }
+
+    fn handle_attack(&mut self, _delta: f64, moving_object: &mut MovingObject) {
+        if !moving_object.on_ground {
+            self.current_state = CharacterState::Jump;
+            return;
+        }
+
+        if self.animation_manager.get_animator("attack".to_string()).is_finished() {
+            self.current_state = CharacterState::Stand;
         }
     }
 
-    fn handle_jump(&mut self, _delta: f64, moving_object: &mut MovingObject) {
-        self.current_animator = "jump".to_string();
+    // Up grabs a ladder the character is in front of, down climbs onto a ladder below its feet.
+    fn start_climb(&mut self, map: &Map, moving_object: &mut MovingObject) -> bool {
+        if self.climb_cooldown > 0.0 {
+            return false;
+        }
+
+        let climb_up = self.pressed_up && moving_object.ladder_at_feet(map, -1.0);
+        let climb_down = self.pressed_drop && moving_object.on_ground && moving_object.ladder_at_feet(map, 1.0);
+        if !climb_up && !climb_down {
+            return false;
+        }
+
+        moving_object.grab_ladder(map, if climb_up { -1.0 } else { 1.0 });
+        self.current_state = CharacterState::Climb;
+        true
+    }
+
+    fn handle_climb(&mut self, _delta: f64, map: &Map, moving_object: &mut MovingObject) {
+        if self.pressed_jump {
+            let direction = if self.pressed_left { -1.0 } else if self.pressed_right { 1.0 } else { 0.0 };
+            if direction != 0.0 {
+                self.turned_back = direction < 0.0;
+            }
+
+            moving_object.stop_climbing();
+            moving_object.speed = [direction * moving_object.movement().max_speed, 0.0];
+            moving_object.jump();
+            self.climb_cooldown = config::CLIMB_REGRAB_TIME;
+            self.current_state = CharacterState::Jump;
+            return;
+        }
+
+        if !moving_object.ladder_at_feet(map, -1.0) {
+            // Climbed over the top, the character stands on the ladder, otherwise it fell off the bottom.
+            moving_object.stop_climbing();
+            if moving_object.ladder_at_feet(map, 1.0) {
+                moving_object.stand_on_ladder(map);
+                self.current_state = CharacterState::Stand;
+            } else {
+                self.current_state = if moving_object.on_ground { CharacterState::Stand } else { CharacterState::Jump };
+            }
+            return;
+        }
+
+        if moving_object.on_ground {
+            moving_object.stop_climbing();
+            self.current_state = CharacterState::Stand;
+            return;
+        }
+
+        let direction = match (self.pressed_up, self.pressed_drop) {
+            (true, false) => -1.0,
+            (false, true) => 1.0,
+            _ => 0.0
+        };
+        moving_object.climb(direction * config::CLIMB_SPEED);
+    }
+
+    fn handle_jump(&mut self, _delta: f64, map: &Map, moving_object: &mut MovingObject) {
+        if self.start_climb(map, moving_object) {
+            return;
+        }
+
         if moving_object.on_ground {
             moving_object.stop_falling();
             self.current_state = CharacterState::Stand;
@@ -131,7 +309,7 @@ impl Character {
             if moving_object.pushes_left_wall {
                 moving_object.stop();
             } else {
-                moving_object.move_right(0.7);
+                moving_object.move_right(config::AIR_CONTROL);
             }
         }
         else if self.pressed_left {
@@ -139,35 +317,53 @@ impl Character {
             if moving_object.pushes_left_wall {
                 moving_object.stop();
             } else {
-                moving_object.move_left(0.7);
+                moving_object.move_left(config::AIR_CONTROL);
             }
         }
 
         moving_object.falling();
     }
 
-    pub fn render(&mut self, ctx: &Context, gl: &mut GlGraphics, moving_object: &mut MovingObject) {
-
-        let mut color = colors::BLUE;	
-
-        let character_x = moving_object.position[0];	
-        let character_y = moving_object.position[1];
+    pub fn ghost_frame(&self, position: Vec2d) -> GhostFrame {
+        GhostFrame {
+            position,
+            animation: self.current_animator.clone(),
+            frame: self.animation_manager.get_animator(self.current_animator.to_string()).current_frame(),
+            mirrored: self.turned_back
+        }
+    }
 
-        let point_trans = ctx	
-                .transform	
-                .trans(character_x, character_y);
+    pub fn render_ghost(&mut self, ctx: &Context, gl: &mut GlGraphics, ghost: &GhostFrame, position: Vec2d) {
+        self.animation_manager
+            .get_animator(ghost.animation.to_string())
+            .render_frame(ctx, gl, ghost.frame, position, ghost.mirrored, colors::GHOST)
+    }
 
-        // rectangle(color, [0.0, 0.0, self.box_size_x, self.box_size_y], point_trans, gl);
-        
+    pub fn render(&mut self, ctx: &Context, gl: &mut GlGraphics, moving_object: &mut MovingObject) {
         self.animation_manager
             .get_animator(self.current_animator.to_string())
             .render(ctx, gl, moving_object.position, self.turned_back)
     }
 }
 
+#[derive(Copy, Clone, PartialEq)]
 enum CharacterState {
     Stand,
     Walk,
     Jump,
+    Attack,
+    Climb,
     GrabLedge
+}
+
+impl CharacterState {
+    fn animation(&self) -> &'static str {
+        match self {
+            CharacterState::Stand | CharacterState::GrabLedge => "idle",
+            CharacterState::Walk => "run",
+            CharacterState::Jump => "jump",
+            CharacterState::Attack => "attack",
+            CharacterState::Climb => "climb"
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,182 @@
+use std::path::PathBuf;
+
+use super::gamedata::GameData;
+
+pub static USAGE: &str = "Usage: RustyPlatormer [options]
+
+Options:
+    --level <file>        level to play, relative to the assets directory
+    --window <WxH>        window size, e.g. 1280x720
+    --fullscreen          open a fullscreen window
+    --assets <dir>        assets directory instead of searching for one
+    --debug               start with the debug overlay enabled
+    --record <file>       record inputs to a file
+    --replay <file>       replay inputs recorded with --record
+    --headless <ticks>    run the level without a window for a number of ticks and print its state
+    --help                print this message";
+
+pub struct Options {
+    pub level: Option<String>,
+    pub window_size: [u32; 2],
+    pub fullscreen: bool,
+    pub assets: Option<PathBuf>,
+    pub debug_overlay: bool,
+    pub record: Option<PathBuf>,
+    pub replay: Option<PathBuf>,
+    pub headless: Option<u64>,
+    pub help: bool
+}
+
+impl Options {
+    pub fn new() -> Options {
+        Options {
+            level: None,
+            window_size: [1120, 800],
+            fullscreen: false,
+            assets: None,
+            debug_overlay: false,
+            record: None,
+            replay: None,
+            headless: None,
+            help: false
+        }
+    }
+
+    pub fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Options, String> {
+        let mut options = Options::new();
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("{} needs a value", arg));
+
+            match arg.as_str() {
+                "--level" => options.level = Some(value()?),
+                "--window" => options.window_size = parse_size(&value()?)?,
+                "--fullscreen" => options.fullscreen = true,
+                "--assets" => options.assets = Some(PathBuf::from(value()?)),
+                "--debug" => options.debug_overlay = true,
+                "--record" => options.record = Some(PathBuf::from(value()?)),
+                "--replay" => options.replay = Some(PathBuf::from(value()?)),
+                "--headless" => {
+                    let ticks = value()?;
+                    options.headless = Some(ticks.parse().map_err(|_| format!("invalid tick count {}", ticks))?);
+                },
+                "--help" | "-h" => options.help = true,
+                _ => return Err(format!("unknown option {}", arg))
+            }
+        }
+
+        if options.record.is_some() && options.replay.is_some() {
+            return Err("--record and --replay can't be used together".to_string());
+        }
+        Ok(options)
+    }
+
+    // Recorded and replayed runs start right in the level, so ticks line up between them.
+    pub fn starts_in_level(&self) -> bool {
+        self.level.is_some() || self.record.is_some() || self.replay.is_some() || self.headless.is_some()
+    }
+
+    pub fn apply(&self, data: &mut GameData) {
+        if let Some(level) = &self.level {
+            data.level = level.clone();
+        }
+        data.debug_overlay = self.debug_overlay;
+        data.persist = self.replay.is_none() && self.headless.is_none();
+    }
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options::new()
+    }
+}
+
+fn parse_size(size: &str) -> Result<[u32; 2], String> {
+    let invalid = || format!("invalid window size {}, expected WIDTHxHEIGHT", size);
+
+    let mut parts = size.split('x');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(width), Some(height), None) => Ok([
+            width.parse().map_err(|_| invalid())?,
+            height.parse().map_err(|_| invalid())?
+        ]),
+        _ => Err(invalid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        Options::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn no_arguments_use_the_defaults() {
+        let options = parse(&[]).unwrap();
+
+        assert_eq!(options.level, None);
+        assert_eq!(options.window_size, [1120, 800]);
+        assert!(!options.fullscreen);
+        assert!(!options.starts_in_level());
+    }
+
+    #[test]
+    fn parses_every_option() {
+        let options = parse(&[
+            "--level", "other.map",
+            "--window", "1280x720",
+            "--fullscreen",
+            "--assets", "/tmp/assets",
+            "--debug",
+            "--replay", "run.yaml",
+            "--headless", "600",
+            "--help"
+        ]).unwrap();
+
+        assert_eq!(options.level.as_deref(), Some("other.map"));
+        assert_eq!(options.window_size, [1280, 720]);
+        assert!(options.fullscreen);
+        assert_eq!(options.assets, Some(PathBuf::from("/tmp/assets")));
+        assert!(options.debug_overlay);
+        assert_eq!(options.replay, Some(PathBuf::from("run.yaml")));
+        assert_eq!(options.headless, Some(600));
+        assert!(options.help);
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        assert!(parse(&["--level"]).is_err());
+        assert!(parse(&["--window", "1280"]).is_err());
+        assert!(parse(&["--window", "1280x720x2"]).is_err());
+        assert!(parse(&["--window", "widexhigh"]).is_err());
+        assert!(parse(&["--headless", "soon"]).is_err());
+        assert!(parse(&["--jump"]).is_err());
+        assert!(parse(&["--record", "a.yaml", "--replay", "b.yaml"]).is_err());
+    }
+
+    #[test]
+    fn recorded_runs_start_in_the_level() {
+        assert!(parse(&["--record", "run.yaml"]).unwrap().starts_in_level());
+        assert!(parse(&["--replay", "run.yaml"]).unwrap().starts_in_level());
+        assert!(parse(&["--headless", "10"]).unwrap().starts_in_level());
+        assert!(parse(&["--level", "other.map"]).unwrap().starts_in_level());
+    }
+
+    #[test]
+    fn replays_and_headless_runs_dont_persist() {
+        let mut data = GameData::new();
+        parse(&["--level", "other.map", "--debug"]).unwrap().apply(&mut data);
+        assert_eq!(data.level, "other.map");
+        assert!(data.debug_overlay);
+        assert!(data.persist);
+
+        parse(&["--replay", "run.yaml"]).unwrap().apply(&mut data);
+        assert!(!data.persist);
+
+        let mut data = GameData::new();
+        parse(&["--headless", "10"]).unwrap().apply(&mut data);
+        assert!(!data.persist);
+    }
+}
@@ -5,6 +5,7 @@ use std::cell::{RefCell, RefMut};
 
 use super::moving_object::{MovingObject, CollisionData};
 use super::map::{Map, AreaIndex};
+use super::config;
 
 pub struct Collider {
     grid_area_width: i8,
@@ -14,6 +15,14 @@ pub struct Collider {
     overlapping_areas: Vec<AreaIndex>
 }
 
+// The collider splits the level into square areas of tiles, levels are padded up to whole areas.
+pub fn padded_size(tiles: usize) -> Option<i8> {
+    use std::convert::TryFrom;
+
+    let area = config::COLLIDER_AREA_SIZE as usize;
+    i8::try_from(tiles.div_ceil(area) * area).ok()
+}
+
 impl Collider {
     pub fn new(grid_area_width: i8, grid_area_height: i8, level_width: i8, level_height: i8) -> Collider {
         if level_width % grid_area_width != 0 {
@@ -36,6 +45,17 @@ impl Collider {
         }
     }
 
+    pub fn area_count(&self) -> (i8, i8) {
+        (self.horizontal_area_count, self.vertical_area_count)
+    }
+
+    // Screen rect covered by a grid area.
+    pub fn area_rect(&self, map: &Map, area: &AreaIndex) -> [f64; 4] {
+        let width = self.grid_area_width as f64 * map.tile_size;
+        let height = self.grid_area_height as f64 * map.tile_size;
+        [area.x as f64 * width + map.position[0], area.y as f64 * height + map.position[1], width, height]
+    }
+
     pub fn update_areas(&mut self,
                         object: &mut MovingObject,
                         map: &Map,
@@ -51,6 +71,12 @@ impl Collider {
         self.overlapping_areas.clear();
     }
 
+    // Takes an object out of the grid before it's dropped from the level.
+    pub fn remove(&mut self, objects_in_area: &mut HashMap<AreaIndex, Vec<String>>, object: &mut MovingObject) {
+        self.overlapping_areas.clear();
+        self.remove_object(objects_in_area, object);
+    }
+
     pub fn check_collisions(&self, objects_in_area: &mut HashMap<AreaIndex, Vec<String>>, objects: &mut HashMap<String, MovingObject>) {
         for y in 0..self.vertical_area_count {
             for x in 0..self.horizontal_area_count {
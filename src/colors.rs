@@ -2,4 +2,22 @@ pub static GRAY: [f32; 4] = [0.9, 0.9, 0.9, 1.0];
 pub static DARK_GRAY: [f32; 4] = [0.7, 0.7, 0.7, 1.0];
 pub static BLACK: [f32; 4] = [0.0, 0.0, 0.0, 1.0];
 pub static RED: [f32; 4] = [1.0, 0.0, 0.0, 1.0];
-pub static BLUE: [f32; 4] = [0.0, 0.0, 1.0, 1.0];
\ No newline at end of file
+pub static GREEN: [f32; 4] = [0.0, 0.6, 0.0, 1.0];
+pub static BLUE: [f32; 4] = [0.0, 0.0, 1.0, 1.0];
+pub static OVERLAY: [f32; 4] = [1.0, 1.0, 1.0, 0.6];
+pub static GHOST: [f32; 4] = [1.0, 1.0, 1.0, 0.4];
+pub static WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+pub static GOLD: [f32; 4] = [1.0, 0.8, 0.0, 1.0];
+pub static DEBUG_AABB: [f32; 4] = [1.0, 0.0, 1.0, 1.0];
+pub static DEBUG_SENSOR: [f32; 4] = [1.0, 0.5, 0.0, 1.0];
+pub static DEBUG_CONTACT: [f32; 4] = [1.0, 0.0, 0.0, 1.0];
+pub static DEBUG_GRID: [f32; 4] = [0.0, 0.6, 0.6, 0.8];
+pub static EDITOR_BLOCK: [f32; 4] = [0.8, 0.2, 0.2, 0.35];
+pub static EDITOR_ONE_WAY: [f32; 4] = [0.2, 0.2, 0.8, 0.35];
+pub static EDITOR_GRID: [f32; 4] = [0.0, 0.0, 0.0, 0.15];
+pub static EDITOR_CURSOR: [f32; 4] = [1.0, 1.0, 0.0, 1.0];
+pub static PROJECTILE: [f32; 4] = [1.0, 0.4, 0.1, 1.0];
+pub static ENEMY_HIT: [f32; 4] = [1.0, 0.3, 0.3, 1.0];
+pub static SPRING: [f32; 4] = [0.2, 0.9, 0.3, 0.6];
+pub static CONVEYOR: [f32; 4] = [0.9, 0.6, 0.1, 0.6];
+pub static LADDER: [f32; 4] = [0.6, 0.4, 0.2, 0.6];
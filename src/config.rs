@@ -9,6 +9,57 @@ pub static ACCELERATION: f64 = 1000.0;
 pub static FRICTION: f64 = 0.7;
 pub static JUMP_SPEED: f64 = 350.0;
 pub static GRAVITY: f64 = 400.0;
+pub static FALL_GRAVITY_FACTOR: f64 = 7.5;
+pub static AIR_CONTROL: f64 = 0.7;
 pub static BACKGROUND_PARRALAX_FACTOR: f64 = 0.7;
 pub static MAP_TILES_PARRALAX_FACTOR: f64 = 1.2;
 
+
+pub static START_LIVES: i32 = 3;
+pub static HUD_FONT_SIZE: u32 = 20;
+pub static HUD_MARGIN: f64 = 10.0;
+pub static MENU_FONT_SIZE: u32 = 32;
+pub static TITLE_FONT_SIZE: u32 = 56;
+pub static FIRST_LEVEL: &str = "level.map";
+pub static SAVE_DIRECTORY: &str = "RustyPlatformer";
+pub static SAVE_FILE: &str = "save.yaml";
+pub static HIGH_SCORE_ENTRIES: usize = 10;
+pub static MAX_USERNAME_LENGTH: usize = 12;
+pub static TIME_BONUS: i32 = 10000;
+pub static TIME_BONUS_PER_SECOND: f64 = 50.0;
+pub static LIFE_BONUS: i32 = 500;
+pub static ASSET_MANIFEST: &str = "manifest.yaml";
+pub static TEXTURES_PER_UPDATE: usize = 2;
+pub static LOADING_BAR_HEIGHT: f64 = 24.0;
+pub static DEV_MODE_VAR: &str = "RUSTY_PLATFORMER_DEV";
+pub static ASSET_POLL_INTERVAL: f64 = 0.5;
+pub static PICKUP_SCORE: i32 = 100;
+pub static PICKUP_SIZE: f64 = 16.0;
+pub static DEBUG_FONT_SIZE: u32 = 12;
+pub static EDITOR_SCROLL_SPEED: f64 = 600.0;
+pub static EDITOR_HISTORY: usize = 100;
+pub static CHARACTER_SIZE: [f64; 2] = [50.0, 50.0];
+pub static COLLIDER_AREA_SIZE: i8 = 8;
+pub static ENEMY_ARRIVE_DISTANCE: f64 = 4.0;
+pub static ENEMY_CHASE_RANGE: f64 = 600.0;
+pub static ENEMY_REPATH_INTERVAL: f64 = 1.0;
+pub static PROJECTILE_SPEED: f64 = 600.0;
+pub static PROJECTILE_SIZE: f64 = 8.0;
+pub static PROJECTILE_LIFETIME: f64 = 1.5;
+pub static PROJECTILE_BOUNCES: u32 = 1;
+pub static PROJECTILE_DAMAGE: i32 = 1;
+pub static SHOOT_COOLDOWN: f64 = 0.3;
+pub static ENEMY_HEALTH: i32 = 3;
+pub static ENEMY_HIT_TIME: f64 = 0.3;
+pub static ENEMY_SCORE: i32 = 200;
+pub static MELEE_RANGE: f64 = 30.0;
+pub static MELEE_HIT_FRAMES: (usize, usize) = (1, 3);
+pub static MELEE_DAMAGE: i32 = 2;
+pub static MELEE_KNOCKBACK: [f64; 2] = [450.0, -200.0];
+pub static PROJECTILE_KNOCKBACK: f64 = 150.0;
+pub static EXTERNAL_SPEED_DAMPING: f64 = 4.0;
+pub static MIN_EXTERNAL_SPEED: f64 = 1.0;
+pub static SPRING_IMPULSE: f64 = 1600.0;
+pub static CONVEYOR_SPEED: f64 = 100.0;
+pub static CLIMB_SPEED: f64 = 120.0;
+pub static CLIMB_REGRAB_TIME: f64 = 0.3;
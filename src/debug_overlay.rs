@@ -0,0 +1,132 @@
+use opengl_graphics::{GlGraphics, GlyphCache};
+use graphics::Context;
+use std::collections::HashMap;
+
+use super::moving_object::MovingObject;
+use super::collider::Collider;
+use super::map::{Map, AreaIndex};
+use super::textwriter::TextWriter;
+use super::colors;
+use super::config;
+
+const SENSOR_SIZE: f64 = 4.0;
+
+pub struct DebugOverlay {
+    pub enabled: bool,
+    text_writer: TextWriter
+}
+
+impl DebugOverlay {
+    pub fn new(enabled: bool) -> DebugOverlay {
+        DebugOverlay {
+            enabled,
+            text_writer: TextWriter::new()
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        self.enabled = !self.enabled;
+    }
+
+    pub fn render_grid(&mut self,
+                   ctx: &Context,
+                   gl: &mut GlGraphics,
+                   glyphs: &mut GlyphCache,
+                   objects_in_area: &HashMap<AreaIndex, Vec<String>>,
+                   collider: &Collider,
+                   map: &Map
+    ) {
+        use graphics::*;
+
+        if !self.enabled {
+            return;
+        }
+
+        let border = Rectangle::new_border(colors::DEBUG_GRID, 1.0);
+        let (columns, rows) = collider.area_count();
+
+        for y in 0..rows {
+            for x in 0..columns {
+                let area = collider.area_rect(map, &AreaIndex { x, y });
+                border.draw(area, &ctx.draw_state, ctx.transform, gl);
+
+                let count = objects_in_area.get(&AreaIndex { x, y }).map_or(0, |keys| keys.len());
+                if count > 0 {
+                    self.text_writer.render_text(
+                        ctx,
+                        gl,
+                        glyphs,
+                        colors::DEBUG_GRID,
+                        config::DEBUG_FONT_SIZE,
+                        area[0] + SENSOR_SIZE,
+                        area[1] + config::DEBUG_FONT_SIZE as f64,
+                        &count.to_string());
+                }
+            }
+        }
+    }
+
+    pub fn render_objects(&mut self, ctx: &Context, gl: &mut GlGraphics, glyphs: &mut GlyphCache, objects: &HashMap<String, MovingObject>) {
+        if !self.enabled {
+            return;
+        }
+
+        for object in objects.values() {
+            self.render_object(ctx, gl, glyphs, object, objects);
+        }
+    }
+
+    fn render_object(&mut self,
+                     ctx: &Context,
+                     gl: &mut GlGraphics,
+                     glyphs: &mut GlyphCache,
+                     object: &MovingObject,
+                     objects: &HashMap<String, MovingObject>
+    ) {
+        use graphics::*;
+
+        let center = object.aabb.center;
+        let half_size = object.aabb.half_size;
+        let bounds = [center[0] - half_size[0], center[1] - half_size[1], half_size[0] * 2.0, half_size[1] * 2.0];
+
+        Rectangle::new_border(colors::DEBUG_AABB, 1.0).draw(bounds, &ctx.draw_state, ctx.transform, gl);
+
+        let (bottom_right, bottom_left, top_right, top_left) = object.get_sensors(object.position);
+        for sensor in [bottom_right, bottom_left, top_right, top_left].iter() {
+            let point = [sensor[0] - SENSOR_SIZE / 2.0, sensor[1] - SENSOR_SIZE / 2.0, SENSOR_SIZE, SENSOR_SIZE];
+            rectangle(colors::DEBUG_SENSOR, point, ctx.transform, gl);
+        }
+
+        for other_key in object.all_colliding_objects.keys() {
+            if let Some(other) = objects.get(other_key) {
+                let other_center = other.aabb.center;
+                line(colors::DEBUG_CONTACT, 1.0, [center[0], center[1], other_center[0], other_center[1]], ctx.transform, gl);
+            }
+        }
+
+        self.text_writer.render_text(
+            ctx,
+            gl,
+            glyphs,
+            colors::DEBUG_AABB,
+            config::DEBUG_FONT_SIZE,
+            bounds[0],
+            bounds[1] - SENSOR_SIZE,
+            &flags(object));
+    }
+}
+
+fn flags(object: &MovingObject) -> String {
+    [
+        (object.on_ground, "ground"),
+        (object.on_one_way_platform, "one-way"),
+        (object.at_ceiling, "ceiling"),
+        (object.pushes_left_wall, "left"),
+        (object.pushes_right_wall, "right")
+    ]
+        .iter()
+        .filter(|(set, _)| *set)
+        .map(|(_, name)| *name)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
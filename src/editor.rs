@@ -0,0 +1,441 @@
+use opengl_graphics::{GlGraphics, GlyphCache};
+use piston::input::{UpdateArgs, Button, MouseButton};
+use piston::input::Button::{Keyboard, Mouse};
+use piston::input::keyboard::Key;
+use graphics::Context;
+use graphics::math::Vec2d;
+use std::rc::Rc;
+
+use super::gamestate::GameState;
+use super::gamedata::GameData;
+use super::states::State;
+use super::texture_loader::TextureLoader;
+use super::map::{Map, TileType, LayerKind};
+use super::map_renderer::MapRenderer;
+use super::map_loader::{MapLoader, LevelObject, ObjectKind};
+use super::textwriter::TextWriter;
+use super::colors;
+use super::config;
+
+// Spawns and enemies are points, drawn with the size of the objects they create.
+const OBJECT_SIZE: f64 = 50.0;
+
+#[derive(Copy, Clone, PartialEq)]
+enum Tool {
+    Tile(TileType),
+    Spawn,
+    Enemy
+}
+
+const TOOLS: [(Key, &str, Tool); 9] = [
+    (Key::D1, "Erase", Tool::Tile(TileType::Empty)),
+    (Key::D2, "Block", Tool::Tile(TileType::Block)),
+    (Key::D3, "One way", Tool::Tile(TileType::OneWay)),
+    (Key::D4, "Spawn", Tool::Spawn),
+    (Key::D5, "Enemy", Tool::Enemy),
+    (Key::D6, "Spring", Tool::Tile(TileType::Spring)),
+    (Key::D7, "Conveyor <", Tool::Tile(TileType::ConveyorLeft)),
+    (Key::D8, "Conveyor >", Tool::Tile(TileType::ConveyorRight)),
+    (Key::D9, "Ladder", Tool::Tile(TileType::Ladder))
+];
+
+struct Snapshot {
+    tiles: Vec<Vec<TileType>>,
+    objects: Vec<LevelObject>
+}
+
+pub struct Editor {
+    map: Map,
+    map_renderer: MapRenderer,
+    objects: Vec<LevelObject>,
+    map_loader: Rc<MapLoader>,
+    text_writer: TextWriter,
+    tool: usize,
+    cursor: Vec2d,
+    painting: Option<MouseButton>,
+    stroke_changed: bool,
+    scroll_left: bool,
+    scroll_right: bool,
+    ctrl: bool,
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    dirty: bool,
+    message: String,
+    confirm_leave: bool,
+    leave: bool,
+    level: String,
+    game_data: Option<GameData>
+}
+
+impl Editor {
+    pub fn new(texture_loader: Rc<TextureLoader>, map_loader: Rc<MapLoader>, game_data: GameData) -> Editor {
+        let tile_size = config::TILE_SIZE as f64;
+        let mut level = map_loader
+            .load_level(&game_data.level, tile_size)
+            .unwrap_or_else(|e| panic!("can't load level {}: {}", game_data.level, e));
+        level.add_default_objects(tile_size);
+
+        let (width, height) = level.size().unwrap_or_else(|e| panic!("can't load level {}: {}", game_data.level, e));
+        let mut map = Map::new(level.tiles, [0.0, 0.0], width, height, tile_size);
+        if let Some((tilesets, layers)) = level.layers {
+            map.set_layers(tilesets, layers);
+        }
+        let map_renderer = MapRenderer::new(&map, &texture_loader);
+
+        let message = if MapLoader::is_editable(&game_data.level) {
+            String::new()
+        } else {
+            "Tiled maps can't be saved from the editor".to_string()
+        };
+
+        Editor {
+            map,
+            map_renderer,
+            objects: level.objects,
+            map_loader,
+            text_writer: TextWriter::new(),
+            tool: 1,
+            cursor: [0.0, 0.0],
+            painting: None,
+            stroke_changed: false,
+            scroll_left: false,
+            scroll_right: false,
+            ctrl: false,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            dirty: false,
+            message,
+            confirm_leave: false,
+            leave: false,
+            level: game_data.level.clone(),
+            game_data: Some(game_data)
+        }
+    }
+
+    fn world_cursor(&self) -> Vec2d {
+        [self.cursor[0] - self.map.position[0], self.cursor[1] - self.map.position[1]]
+    }
+
+    fn tile_at_cursor(&self) -> Option<(usize, usize)> {
+        let world = self.world_cursor();
+        if world[0] < 0.0 || world[1] < 0.0 {
+            return None;
+        }
+
+        let column = (world[0] / self.map.tile_size) as usize;
+        let row = (world[1] / self.map.tile_size) as usize;
+
+        if row < self.map.tiles.len() && column < self.map.tiles[row].len() {
+            Some((column, row))
+        } else {
+            None
+        }
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            tiles: self.map.tiles.clone(),
+            objects: self.objects.clone()
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.map.replace_tiles(snapshot.tiles).unwrap();
+        self.objects = snapshot.objects;
+        self.dirty = true;
+    }
+
+    fn begin_stroke(&mut self, button: MouseButton) {
+        self.undo.push(self.snapshot());
+        if self.undo.len() > config::EDITOR_HISTORY {
+            self.undo.remove(0);
+        }
+        self.painting = Some(button);
+        self.stroke_changed = false;
+        self.apply_stroke();
+    }
+
+    // A stroke that didn't change anything shouldn't take an undo step.
+    fn end_stroke(&mut self) {
+        if self.painting.take().is_some() && !self.stroke_changed {
+            self.undo.pop();
+        }
+    }
+
+    fn apply_stroke(&mut self) {
+        let changed = match (self.painting, TOOLS[self.tool].2) {
+            (Some(MouseButton::Left), Tool::Tile(tile)) => self.paint(tile),
+            (Some(MouseButton::Left), Tool::Spawn) => self.place(ObjectKind::Spawn),
+            (Some(MouseButton::Left), Tool::Enemy) => self.place(ObjectKind::Enemy),
+            (Some(MouseButton::Right), _) => self.remove_object() || self.paint(TileType::Empty),
+            _ => false
+        };
+
+        if changed {
+            self.stroke_changed = true;
+            self.dirty = true;
+            self.redo.clear();
+        }
+    }
+
+    fn paint(&mut self, tile: TileType) -> bool {
+        match self.tile_at_cursor() {
+            Some((column, row)) if self.map.tiles[row][column] != tile => {
+                self.map.set_tile(column, row, tile);
+                true
+            },
+            _ => false
+        }
+    }
+
+    // Objects snap to the tile under the cursor, a level has a single spawn.
+    fn place(&mut self, kind: ObjectKind) -> bool {
+        let (column, row) = match self.tile_at_cursor() {
+            Some(tile) => tile,
+            None => return false
+        };
+
+        let position = [column as f64 * self.map.tile_size, row as f64 * self.map.tile_size];
+        if self.objects.iter().any(|object| object.kind == kind && object.position == position) {
+            return false;
+        }
+
+        if kind == ObjectKind::Spawn {
+            self.objects.retain(|object| object.kind != ObjectKind::Spawn);
+        }
+        self.objects.push(LevelObject::new(kind, position, [0.0, 0.0]));
+        true
+    }
+
+    // Only enemies and pickups can be removed, spawns are moved and triggers cover whole regions.
+    fn remove_object(&mut self) -> bool {
+        let cursor = self.world_cursor();
+        let hit = self.objects
+            .iter()
+            .rposition(|object| matches!(object.kind, ObjectKind::Enemy | ObjectKind::Pickup) && contains(object_rect(object), cursor));
+
+        match hit {
+            Some(index) => {
+                self.objects.remove(index);
+                true
+            },
+            None => false
+        }
+    }
+
+    fn undo(&mut self) {
+        if let Some(snapshot) = self.undo.pop() {
+            self.redo.push(self.snapshot());
+            self.restore(snapshot);
+        }
+    }
+
+    fn redo(&mut self) {
+        if let Some(snapshot) = self.redo.pop() {
+            self.undo.push(self.snapshot());
+            self.restore(snapshot);
+        }
+    }
+
+    fn save(&mut self) {
+        if !MapLoader::is_editable(&self.level) {
+            self.message = "Tiled maps can't be saved from the editor".to_string();
+            return;
+        }
+
+        self.message = match self.map_loader.save_map(&self.level, &self.map.tiles, &self.objects) {
+            Ok(()) => {
+                self.dirty = false;
+                format!("Saved {}", self.level)
+            },
+            Err(error) => error
+        };
+    }
+
+    fn leave(&mut self, confirmed: bool) {
+        if self.dirty && !confirmed {
+            self.message = "Unsaved changes, press Escape again to discard them".to_string();
+            self.confirm_leave = true;
+            return;
+        }
+        self.leave = true;
+    }
+
+    fn scroll(&mut self, delta: f64) {
+        let direction = self.scroll_right as i8 - self.scroll_left as i8;
+        let min_x = -(self.map.size()[0] - config::BOARD_SIZE_X as f64).max(0.0);
+
+        self.map.position[0] = (self.map.position[0] - direction as f64 * config::EDITOR_SCROLL_SPEED * delta).clamp(min_x, 0.0);
+    }
+
+    fn render_collision(&self, ctx: &Context, gl: &mut GlGraphics) {
+        use graphics::*;
+
+        let tile_size = self.map.tile_size;
+        let grid = Rectangle::new_border(colors::EDITOR_GRID, 0.5);
+
+        for (row, columns) in self.map.tiles.iter().enumerate() {
+            for (column, tile) in columns.iter().enumerate() {
+                let rect = [
+                    column as f64 * tile_size + self.map.position[0],
+                    row as f64 * tile_size + self.map.position[1],
+                    tile_size,
+                    tile_size
+                ];
+
+                if rect[0] + tile_size < 0.0 || rect[0] > config::BOARD_SIZE_X as f64 {
+                    continue;
+                }
+
+                match tile {
+                    TileType::Block => rectangle(colors::EDITOR_BLOCK, rect, ctx.transform, gl),
+                    TileType::OneWay => rectangle(colors::EDITOR_ONE_WAY, rect, ctx.transform, gl),
+                    TileType::Spring => rectangle(colors::SPRING, rect, ctx.transform, gl),
+                    TileType::ConveyorLeft | TileType::ConveyorRight => rectangle(colors::CONVEYOR, rect, ctx.transform, gl),
+                    TileType::Ladder => rectangle(colors::LADDER, rect, ctx.transform, gl),
+                    TileType::Empty => {}
+                }
+                grid.draw(rect, &ctx.draw_state, ctx.transform, gl);
+            }
+        }
+
+        if let Some((column, row)) = self.tile_at_cursor() {
+            let rect = [
+                column as f64 * tile_size + self.map.position[0],
+                row as f64 * tile_size + self.map.position[1],
+                tile_size,
+                tile_size
+            ];
+            Rectangle::new_border(colors::EDITOR_CURSOR, 1.0).draw(rect, &ctx.draw_state, ctx.transform, gl);
+        }
+    }
+
+    fn render_objects(&mut self, ctx: &Context, gl: &mut GlGraphics, glyphs: &mut GlyphCache) {
+        use graphics::*;
+
+        for object in self.objects.iter() {
+            let world = object_rect(object);
+            let rect = [world[0] + self.map.position[0], world[1] + self.map.position[1], world[2], world[3]];
+
+            let (color, label) = match object.kind {
+                ObjectKind::Spawn => (colors::GREEN, "Spawn"),
+                ObjectKind::Enemy => (colors::RED, "Enemy"),
+                ObjectKind::Pickup => (colors::GOLD, "Pickup"),
+                ObjectKind::Trigger => (colors::BLUE, "Trigger")
+            };
+
+            Rectangle::new_border(color, 1.5).draw(rect, &ctx.draw_state, ctx.transform, gl);
+            self.text_writer.render_text(
+                ctx,
+                gl,
+                glyphs,
+                color,
+                config::DEBUG_FONT_SIZE,
+                rect[0] + 2.0,
+                rect[1] + config::DEBUG_FONT_SIZE as f64,
+                label);
+        }
+    }
+}
+
+fn object_rect(object: &LevelObject) -> [f64; 4] {
+    let size = if object.size[0] > 0.0 && object.size[1] > 0.0 {
+        object.size
+    } else {
+        [OBJECT_SIZE, OBJECT_SIZE]
+    };
+    [object.position[0], object.position[1], size[0], size[1]]
+}
+
+fn contains(rect: [f64; 4], point: Vec2d) -> bool {
+    point[0] >= rect[0] && point[0] < rect[0] + rect[2] && point[1] >= rect[1] && point[1] < rect[1] + rect[3]
+}
+
+impl GameState for Editor {
+    fn render(&mut self, ctx: &Context, gl: &mut GlGraphics, glyphs: &mut GlyphCache) {
+        let view = [-self.map.position[0], -self.map.position[1], config::BOARD_SIZE_X as f64, config::BOARD_SIZE_Y as f64];
+
+        for kind in [LayerKind::Background, LayerKind::Collision, LayerKind::Foreground].iter() {
+            self.map_renderer.render(ctx, gl, &self.map, *kind, view);
+        }
+        self.render_collision(ctx, gl);
+        self.render_objects(ctx, gl, glyphs);
+    }
+
+    fn render_hud(&mut self, ctx: &Context, gl: &mut GlGraphics, glyphs: &mut GlyphCache) {
+        let tools: Vec<String> = TOOLS
+            .iter()
+            .enumerate()
+            .map(|(i, (_, name, _))| if i == self.tool { format!("[{} {}]", i + 1, name) } else { format!("{} {}", i + 1, name) })
+            .collect();
+
+        let status = format!("{}{}", tools.join("  "), if self.dirty { "  *" } else { "" });
+        let help = "Mouse: paint / erase   A/D: scroll   Ctrl+Z/Y: undo/redo   Ctrl+S: save   Esc: play";
+
+        let lines = [status.as_str(), help, self.message.as_str()];
+        for (i, line) in lines.iter().enumerate() {
+            self.text_writer.render_text(
+                ctx,
+                gl,
+                glyphs,
+                colors::BLACK,
+                config::HUD_FONT_SIZE,
+                config::HUD_MARGIN,
+                config::HUD_MARGIN + config::HUD_FONT_SIZE as f64 * (i + 1) as f64,
+                line);
+        }
+    }
+
+    fn update(&mut self, args: &UpdateArgs) -> State<GameData> {
+        if self.leave {
+            return State::Game(self.game_data.take().unwrap());
+        }
+
+        self.scroll(args.dt);
+        State::None
+    }
+
+    fn mouse_move(&mut self, position: [f64; 2]) {
+        self.cursor = position;
+        self.apply_stroke();
+    }
+
+    fn key_press(&mut self, args: &Button) {
+        if let Keyboard(key) = *args {
+            if let Some(tool) = TOOLS.iter().position(|(tool_key, _, _)| *tool_key == key) {
+                self.tool = tool;
+                return;
+            }
+        }
+
+        // Any other key than a second Escape cancels leaving.
+        let confirmed = std::mem::take(&mut self.confirm_leave);
+        if confirmed {
+            self.message.clear();
+        }
+
+        match *args {
+            Mouse(button) if self.painting.is_none() => self.begin_stroke(button),
+            Keyboard(Key::LCtrl) | Keyboard(Key::RCtrl) => self.ctrl = true,
+            Keyboard(Key::Z) if self.ctrl => self.undo(),
+            Keyboard(Key::Y) if self.ctrl => self.redo(),
+            Keyboard(Key::S) if self.ctrl => self.save(),
+            Keyboard(Key::A) | Keyboard(Key::Left) => self.scroll_left = true,
+            Keyboard(Key::D) | Keyboard(Key::Right) => self.scroll_right = true,
+            Keyboard(Key::Escape) | Keyboard(Key::F2) => {
+                self.leave(confirmed);
+            },
+            _ => {}
+        }
+    }
+
+    fn key_release(&mut self, args: &Button) {
+        match *args {
+            Mouse(button) if self.painting == Some(button) => self.end_stroke(),
+            Keyboard(Key::LCtrl) | Keyboard(Key::RCtrl) => self.ctrl = false,
+            Keyboard(Key::A) | Keyboard(Key::Left) => self.scroll_left = false,
+            Keyboard(Key::D) | Keyboard(Key::Right) => self.scroll_right = false,
+            _ => {}
+        }
+    }
+}
@@ -11,58 +11,229 @@ use graphics::Context;
 use super::texture_loader::TextureLoader;
 use super::animation_manager::AnimationManager;
 use super::colors;
-use super::map::Map;
+use super::map::{Map, TileType};
+use super::nav_graph::{NavGraph, Edge, EdgeKind};
+use graphics::math::Vec2d;
+use std::collections::VecDeque;
 
 pub struct Enemy {
     current_animator: String,
     animation_manager: AnimationManager,
     turned_back: bool,
     box_size_x: f64,
-    box_size_y: f64
+    box_size_y: f64,
+    path: VecDeque<Edge>,
+    in_air: bool,
+    health: i32,
+    hit_timer: f64
 }
 
 impl Enemy {
     pub fn new(tex_loader: Rc<TextureLoader>, box_size_x: f64, box_size_y: f64) -> Enemy {
 
         let mut animation_manager = AnimationManager::new(tex_loader);
-        animation_manager.add_sequence("idle".to_string(), "Enemy/idle", 0.1, 1, 9, [box_size_x, box_size_y]);
+        let atlas = animation_manager.add_atlas("Enemy/enemy.atlas.yaml");
+        animation_manager.add_atlas_sequence("idle".to_string(), &atlas, "idle", 0.1, [box_size_x, box_size_y]);
 
         Enemy { 
             current_animator: "idle".to_string(),
             animation_manager: animation_manager,
             turned_back: false,
             box_size_x: box_size_x,
-            box_size_y: box_size_y
+            box_size_y: box_size_y,
+            path: VecDeque::new(),
+            in_air: false,
+            health: config::ENEMY_HEALTH,
+            hit_timer: 0.0
         }
     }
 
-    pub fn character_update(&mut self, delta: f64, map: &Map, moving_object: &mut MovingObject){
+    pub fn character_update(&mut self, delta: f64, map: &Map, graph: Option<&NavGraph>, moving_object: &mut MovingObject){
 
         self.handle_stand(delta, moving_object);
+        // A hit enemy is stunned for a moment and doesn't walk its path.
+        if self.hit_timer > 0.0 {
+            self.hit_timer -= delta;
+            if moving_object.on_ground {
+                moving_object.stop_falling();
+            } else {
+                moving_object.falling();
+            }
+        } else if let Some(graph) = graph {
+            self.follow_path(graph, map, moving_object);
+        }
 
         moving_object.update_physics(delta, &map);
         self.animation_manager.get_animator(self.current_animator.to_string()).next(delta);
     }
 
+    // Finds a path to a world position, the enemy walks it in the following updates.
+    // Returns false if the target can't be reached from where the enemy stands.
+    pub fn move_to(&mut self, graph: &NavGraph, map: &Map, moving_object: &MovingObject, target: Vec2d) -> bool {
+        if self.in_air || !moving_object.on_ground {
+            return !self.path.is_empty();
+        }
+
+        let from = graph.node_under(world_position(map, moving_object));
+        let to = graph.landing(map, target).or_else(|| graph.node_under(target));
+
+        match (from, to) {
+            (Some(from), Some(to)) => match graph.find_path(from, to) {
+                Some(path) => {
+                    self.path = path.into();
+                    true
+                },
+                None => {
+                    self.path.clear();
+                    false
+                }
+            },
+            _ => false
+        }
+    }
+
+    pub fn hit(&mut self, damage: i32, knockback: Vec2d, moving_object: &mut MovingObject) {
+        self.health -= damage;
+        self.hit_timer = config::ENEMY_HIT_TIME;
+        self.path.clear();
+        self.in_air = false;
+        if moving_object.climbing {
+            moving_object.stop_climbing();
+        }
+        moving_object.stop();
+        moving_object.apply_impulse(knockback);
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.health <= 0
+    }
+
+    pub fn has_path(&self) -> bool {
+        !self.path.is_empty()
+    }
+
+    fn follow_path(&mut self, graph: &NavGraph, map: &Map, moving_object: &mut MovingObject) {
+        let edge = match self.path.front() {
+            Some(edge) => *edge,
+            None => return
+        };
+        let position = world_position(map, moving_object);
+
+        if moving_object.climbing {
+            self.climb(map, moving_object);
+            return;
+        }
+
+        if self.in_air {
+            // Springs throw the enemy straight back up, that isn't where the edge ends.
+            if moving_object.on_ground && moving_object.ground_tile != TileType::Spring {
+                self.land(graph, moving_object, edge, position);
+            } else {
+                self.steer(moving_object, edge.direction, config::AIR_CONTROL);
+                moving_object.falling();
+            }
+            return;
+        }
+
+        if !moving_object.on_ground {
+            self.in_air = true;
+            if edge.kind == EdgeKind::Fall {
+                moving_object.speed[0] = edge.speed;
+            }
+            return;
+        }
+
+        let target_x = if edge.kind == EdgeKind::Walk { graph.position(edge.to)[0] } else { edge.start[0] };
+        let distance = target_x - position[0];
+
+        if distance.abs() > config::ENEMY_ARRIVE_DISTANCE {
+            self.steer(moving_object, distance.signum(), 1.0);
+            return;
+        }
+
+        match edge.kind {
+            EdgeKind::Walk => {
+                self.path.pop_front();
+                if self.path.is_empty() {
+                    moving_object.stop();
+                }
+            },
+            EdgeKind::Jump => {
+                moving_object.speed[0] = edge.speed;
+                moving_object.jump();
+                self.in_air = true;
+            },
+            // The start is already past the ledge, keep walking away from the node until the ground ends.
+            EdgeKind::Fall => {
+                let direction = edge.start[0] - graph.position(edge.from)[0];
+                self.steer(moving_object, direction.signum(), 1.0);
+            },
+            EdgeKind::Drop => {
+                moving_object.stop();
+                moving_object.drop();
+                self.in_air = true;
+            },
+            EdgeKind::Climb => {
+                let offset = if graph.position(edge.to)[1] < position[1] { -1.0 } else { 1.0 };
+                moving_object.stop();
+                moving_object.grab_ladder(map, offset);
+            }
+        }
+    }
+
+    // Climbs like `Character::handle_climb` does and lands once the ladder ends.
+    fn climb(&mut self, map: &Map, moving_object: &mut MovingObject) {
+        if moving_object.ladder_at_feet(map, -1.0) && !moving_object.on_ground {
+            return;
+        }
+
+        moving_object.stop_climbing();
+        if moving_object.ladder_at_feet(map, 1.0) {
+            moving_object.stand_on_ladder(map);
+        }
+        self.in_air = true;
+    }
+
+    // Physics don't match the graph exactly, so landing somewhere else finds a new path next time.
+    fn land(&mut self, graph: &NavGraph, moving_object: &mut MovingObject, edge: Edge, position: Vec2d) {
+        self.in_air = false;
+        moving_object.stop_falling();
+        self.path.pop_front();
+
+        if graph.node_under(position) != Some(edge.to) {
+            self.path.clear();
+        }
+        if self.path.is_empty() {
+            moving_object.stop();
+        }
+    }
+
+    fn steer(&mut self, moving_object: &mut MovingObject, direction: f64, factor: f64) {
+        if direction > 0.0 {
+            self.turned_back = false;
+            moving_object.move_right(factor);
+        } else if direction < 0.0 {
+            self.turned_back = true;
+            moving_object.move_left(factor);
+        }
+    }
+
     fn handle_stand(&mut self, _delta: f64, moving_object: &mut MovingObject) {
         self.current_animator = "idle".to_string();
     }
 
     pub fn render(&mut self, ctx: &Context, gl: &mut GlGraphics, moving_object: &mut MovingObject) {
-        
-        let mut color = colors::BLUE;
-
-        let character_x = moving_object.position[0];	
-        let character_y = moving_object.position[1];
+        let mut animator = self.animation_manager.get_animator(self.current_animator.to_string());
 
-        let point_trans = ctx	
-                .transform	
-                .trans(character_x, character_y);
-
-        // rectangle(color, [0.0, 0.0, self.box_size_x, self.box_size_y], point_trans, gl);
-        
-        self.animation_manager
-            .get_animator(self.current_animator.to_string())
-            .render(ctx, gl, moving_object.position, self.turned_back)
+        if self.hit_timer > 0.0 {
+            let frame = animator.current_frame();
+            animator.render_frame(ctx, gl, frame, moving_object.position, self.turned_back, colors::ENEMY_HIT);
+        } else {
+            animator.render(ctx, gl, moving_object.position, self.turned_back);
+        }
     }
-}
\ No newline at end of file
+}
+
+fn world_position(map: &Map, moving_object: &MovingObject) -> Vec2d {
+    [moving_object.position[0] - map.position[0], moving_object.position[1] - map.position[1]]
+}
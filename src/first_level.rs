@@ -9,20 +9,41 @@ use std::cell::RefCell;
 
 use super::gamestate::GameState;
 use super::renderable::Renderable;
-use super::gamedata::GameData;
+use super::gamedata::{GameData, LevelResult};
 use super::states::State;
 use super::texture_loader::TextureLoader;
 use super::background::Background;
 use super::character::Character;
 use super::camera::{Camera, CameraDependentObject};
-use super::map::{Map, TileType, AreaIndex};
+use super::map::{Map, TileType, AreaIndex, LayerKind};
+use super::map_renderer::MapRenderer;
 use std::collections::HashMap;
-use super::map_loader::MapLoader;
+use super::map_loader::{Level, MapLoader, ObjectKind};
 use super::colors;
-use super::collider::Collider;
+use super::collider::{self, Collider};
 use super::moving_object::MovingObject;
 use super::config;
 use super::enemy::Enemy;
+use super::hud::Hud;
+use super::save_data::Checkpoint;
+use super::speedrun::SpeedrunTimer;
+use super::menu::{Menu, MenuInput};
+use super::pickup::Pickup;
+use super::projectile::Projectile;
+use super::trigger::{Trigger, TriggerAction};
+use super::debug_overlay::DebugOverlay;
+use super::nav_graph::NavGraph;
+
+const PLAYER_ID: &str = "1ad31e1d-494a-41fe-bb9c-e7b8b83e59f1";
+
+const RESUME: usize = 0;
+const QUIT_TO_TITLE: usize = 1;
+const QUIT_GAME: usize = 2;
+
+enum PauseAction {
+    QuitToTitle,
+    QuitGame
+}
 
 pub struct FirstLevel {
     background: Background,
@@ -32,14 +53,33 @@ pub struct FirstLevel {
     objects_in_area: HashMap<AreaIndex, Vec<String>>,
     camera: Camera,
     map: Map,
+    map_renderer: MapRenderer,
     collider: Collider,
-    enemy: Enemy,
+    enemies: Vec<(String, Enemy)>,
+    nav_graph: Option<NavGraph>,
+    repath_timer: f64,
+    projectiles: Vec<Projectile>,
+    projectile_count: usize,
+    shoot_cooldown: f64,
+    pickups: Vec<Pickup>,
+    checkpoints: Vec<Trigger>,
+    exits: Vec<Trigger>,
+    hud: Hud,
+    game_data: GameData,
+    paused: bool,
+    pause_menu: Menu,
+    pause_action: Option<PauseAction>,
+    last_checkpoint: Option<usize>,
+    speedrun: SpeedrunTimer,
+    map_loader: Rc<MapLoader>,
+    texture_loader: Rc<TextureLoader>,
+    debug_overlay: DebugOverlay,
+    open_editor: bool,
 }
 
 impl FirstLevel {
-    pub fn new(texture_loader: Rc<TextureLoader>, map_loader: Rc<MapLoader>) -> FirstLevel {
-        let background_texture = texture_loader.load_texture("City Background.png");
-        let foreground_texture = texture_loader.load_texture("City Foreground.png");
+    pub fn new(texture_loader: Rc<TextureLoader>, map_loader: Rc<MapLoader>, mut game_data: GameData) -> Result<FirstLevel, String> {
+        game_data.reset_run();
 
         let mut key_press = Rc::new(RefCell::new(HashMap::new()));
         (*key_press.borrow_mut()).insert(Key::Left, false);
@@ -48,95 +88,541 @@ impl FirstLevel {
         (*key_press.borrow_mut()).insert(Key::A, false);
         (*key_press.borrow_mut()).insert(Key::D, false);
 
-        let map = Map::new(
-            map_loader.load_map("level.map"),
-            [0.0, 0.0],
-            120,
-            33,
-            24.0,
-            Rc::clone(&texture_loader)
-        );
+        let tile_size = config::TILE_SIZE as f64;
+        let mut level = map_loader
+            .load_level(&game_data.level, tile_size)
+            .map_err(|e| format!("can't load level {}: {}", game_data.level, e))?;
+        level.add_default_objects(tile_size);
+        let (width, height) = level.size()?;
 
-        let moving_object = MovingObject::new(
-            [50.0, 300.0],
-            [50.0, 50.0],
-            [0.0, 1080.0],
-            config::ACCELERATION,
-            config::WALK_SPEED,
-            config::JUMP_SPEED,
-            "1ad31e1d-494a-41fe-bb9c-e7b8b83e59f1".to_string());
+        let player_spawn = level.objects_of(ObjectKind::Spawn).next().unwrap().position;
+        let enemy_spawns: Vec<[f64; 2]> = level.objects_of(ObjectKind::Enemy).map(|enemy| enemy.position).collect();
+        let pickups = level.objects_of(ObjectKind::Pickup).map(Pickup::new).collect();
+        let (checkpoints, exits) = FirstLevel::triggers(&level);
 
-        let enemy_object = MovingObject::new(
-            [300.0, 300.0],
-            [50.0, 50.0],
+        let mut map = Map::new(level.tiles, [0.0, 0.0], width, height, tile_size);
+        if let Some((tilesets, layers)) = level.layers {
+            map.set_layers(tilesets, layers);
+        }
+        let map_renderer = MapRenderer::new(&map, &texture_loader);
+
+        let moving_object = MovingObject::new(
+            player_spawn,
+            config::CHARACTER_SIZE,
             [0.0, 1080.0],
             config::ACCELERATION,
             config::WALK_SPEED,
             config::JUMP_SPEED,
-            "5c8cd4c5-8d44-4326-bfa2-c803a30109fc".to_string());
+            PLAYER_ID.to_string());
 
         let box_size_x = moving_object.aabb.half_size[0] * 2.0;
         let box_size_y = moving_object.aabb.half_size[1] * 2.0;
 
-        let enemy_box_size_x = enemy_object.aabb.half_size[0] * 2.0;
-        let enemy_box_size_y = enemy_object.aabb.half_size[1] * 2.0;
-
         let mut objects = HashMap::<String, MovingObject>::new();
-        objects.insert("1ad31e1d-494a-41fe-bb9c-e7b8b83e59f1".to_string(), moving_object);
-        objects.insert("5c8cd4c5-8d44-4326-bfa2-c803a30109fc".to_string(), enemy_object);
+        objects.insert(PLAYER_ID.to_string(), moving_object);
+
+        let mut enemies = Vec::new();
+        for (i, spawn) in enemy_spawns.into_iter().enumerate() {
+            let enemy_id = format!("enemy-{}", i);
+            let enemy_object = MovingObject::new(
+                spawn,
+                config::CHARACTER_SIZE,
+                [0.0, 1080.0],
+                config::ACCELERATION,
+                config::WALK_SPEED,
+                config::JUMP_SPEED,
+                enemy_id.clone());
+
+            let enemy_box_size_x = enemy_object.aabb.half_size[0] * 2.0;
+            let enemy_box_size_y = enemy_object.aabb.half_size[1] * 2.0;
+
+            objects.insert(enemy_id.clone(), enemy_object);
+            enemies.push((enemy_id, Enemy::new(Rc::clone(&texture_loader), enemy_box_size_x, enemy_box_size_y)));
+        }
+
+        // Enemies all move the same way, so one graph built for the first serves all of them.
+        let nav_graph = enemies.first().map(|(enemy_id, _)| NavGraph::new(&map, objects[enemy_id].movement()));
 
-        FirstLevel {
-            background: Background::new(background_texture, foreground_texture, 2, 1000.0),
+        let layer_configs = map_loader
+            .load_background(&game_data.level)
+            .map_err(|e| format!("can't load level {}: {}", game_data.level, e))?;
+        let background = Background::new(&texture_loader, &layer_configs);
+        let speedrun = SpeedrunTimer::new(&game_data.level);
+        let debug_overlay = DebugOverlay::new(game_data.debug_overlay);
+
+        let mut level = FirstLevel {
+            background,
             character: Character::new(Rc::clone(&key_press), Rc::clone(&texture_loader), box_size_x, box_size_y),
             camera: Camera::new(460.0, 660.0),
             objects: objects,
             key_press: key_press,
             map: map,
-            collider: Collider::new(8, 8, 120, 32),
+            map_renderer,
+            collider: Collider::new(config::COLLIDER_AREA_SIZE, config::COLLIDER_AREA_SIZE, collider_size(width)?, collider_size(height)?),
             objects_in_area: HashMap::new(),
-            enemy: Enemy::new(Rc::clone(&texture_loader), enemy_box_size_x, enemy_box_size_y),
+            enemies,
+            nav_graph,
+            repath_timer: 0.0,
+            projectiles: Vec::new(),
+            projectile_count: 0,
+            shoot_cooldown: 0.0,
+            pickups,
+            checkpoints,
+            exits,
+            hud: Hud::new(),
+            game_data,
+            paused: false,
+            pause_menu: Menu::new(&["Resume", "Quit to Title", "Quit Game"]),
+            pause_action: None,
+            last_checkpoint: None,
+            speedrun,
+            map_loader,
+            texture_loader,
+            debug_overlay,
+            open_editor: false,
+        };
+
+        let checkpoint = level.game_data.progress.checkpoint
+            .clone()
+            .filter(|checkpoint| checkpoint.level == level.game_data.level);
+
+        if let Some(checkpoint) = checkpoint {
+            level.camera.focus(&mut level.objects, &mut level.map, &mut level.background, checkpoint.position);
+            level.last_checkpoint = Some(checkpoint.index);
+            level.speedrun.invalidate();
+        }
+
+        Ok(level)
+    }
+
+    // Checkpoints and exits, checkpoints ordered from left to right.
+    fn triggers(level: &Level) -> (Vec<Trigger>, Vec<Trigger>) {
+        let mut triggers: Vec<Trigger> = level.objects_of(ObjectKind::Trigger).filter_map(Trigger::from_object).collect();
+        triggers.sort_by(|a, b| a.area[0].total_cmp(&b.area[0]));
+        triggers.into_iter().partition(|trigger| trigger.action == TriggerAction::Checkpoint)
+    }
+
+    // Swaps tiles, layers and, if their file changed, pickups and triggers while the level runs.
+    // Spawns and enemies keep going until the level is restarted.
+    fn reload_level(&mut self, objects_changed: bool) {
+        let tile_size = self.map.tile_size;
+        let mut level = match self.map_loader.load_level(&self.game_data.level, tile_size) {
+            Ok(level) => level,
+            Err(error) => {
+                eprintln!("can't reload {}: {}", self.game_data.level, error);
+                return;
+            }
+        };
+        level.add_default_objects(tile_size);
+
+        if let Err(error) = self.map.replace_tiles(std::mem::take(&mut level.tiles)) {
+            eprintln!("can't reload {}: {}", self.game_data.level, error);
+            return;
+        }
+
+        match level.layers.take() {
+            Some((tilesets, layers)) => self.map.set_layers(tilesets, layers),
+            None => self.map.use_collision_layers()
+        }
+        self.map_renderer = MapRenderer::new(&self.map, &self.texture_loader);
+
+        if let Some(graph) = self.nav_graph.as_mut() {
+            *graph = NavGraph::new(&self.map, graph.movement());
+        }
+
+        if objects_changed {
+            self.pickups = level.objects_of(ObjectKind::Pickup).map(Pickup::new).collect();
+            let (checkpoints, exits) = FirstLevel::triggers(&level);
+            self.checkpoints = checkpoints;
+            self.exits = exits;
         }
     }
+
+    fn reload_background(&mut self) {
+        match self.map_loader.load_background(&self.game_data.level) {
+            Ok(layers) => self.background.set_layers(&self.texture_loader, &layers),
+            Err(error) => eprintln!("can't reload {}: {}", self.game_data.level, error)
+        }
+    }
+
+    fn player_world_position(&self) -> [f64; 2] {
+        let player = &self.objects[PLAYER_ID];
+        [player.position[0] - self.map.position[0], player.position[1]]
+    }
+
+    fn shoot(&mut self) {
+        if self.shoot_cooldown > 0.0 {
+            return;
+        }
+        self.shoot_cooldown = config::SHOOT_COOLDOWN;
+
+        let object_id = format!("projectile-{}", self.projectile_count);
+        self.projectile_count += 1;
+
+        let projectile = Projectile::new(object_id.clone(), config::PROJECTILE_BOUNCES);
+        let object = projectile.object(self.objects[PLAYER_ID].aabb.center, self.character.facing());
+        self.objects.insert(object_id, object);
+        self.projectiles.push(projectile);
+    }
+
+    fn update_projectiles(&mut self, delta: f64) {
+        self.shoot_cooldown -= delta;
+
+        for projectile in self.projectiles.iter_mut() {
+            projectile.update(delta, &self.map, self.objects.get_mut(&projectile.object_id).unwrap());
+        }
+
+        for projectile in self.projectiles.iter().filter(|projectile| projectile.finished) {
+            let mut object = self.objects.remove(&projectile.object_id).unwrap();
+            self.collider.remove(&mut self.objects_in_area, &mut object);
+        }
+        self.projectiles.retain(|projectile| !projectile.finished);
+    }
+
+    // Projectiles touching an enemy, as found by the collider, hurt it and are used up. The
+    // melee swing hurts enemies in its hitbox once per swing.
+    fn hit_enemies(&mut self) {
+        for projectile in self.projectiles.iter_mut().filter(|projectile| !projectile.finished) {
+            let hits = &self.objects[&projectile.object_id].all_colliding_objects;
+            if let Some((enemy_id, enemy)) = self.enemies.iter_mut().find(|(enemy_id, _)| hits.contains_key(enemy_id)) {
+                let knockback = [config::PROJECTILE_KNOCKBACK * self.objects[&projectile.object_id].speed[0].signum(), 0.0];
+                let object = self.objects.get_mut(enemy_id).unwrap();
+                enemy.hit(projectile.damage, knockback, object);
+                projectile.finished = true;
+            }
+        }
+
+        if let Some(hitbox) = self.character.attack_hitbox(&self.objects[PLAYER_ID]) {
+            let knockback = [config::MELEE_KNOCKBACK[0] * self.character.facing(), config::MELEE_KNOCKBACK[1]];
+            let attack_hits = &mut self.character.attack_hits;
+
+            for (enemy_id, enemy) in self.enemies.iter_mut() {
+                let object = self.objects.get_mut(enemy_id).unwrap();
+                if !attack_hits.contains(enemy_id) && hitbox.overlaps_signed(&object.aabb).0 {
+                    enemy.hit(config::MELEE_DAMAGE, knockback, object);
+                    attack_hits.push(enemy_id.clone());
+                }
+            }
+        }
+
+        for (enemy_id, _) in self.enemies.iter().filter(|(_, enemy)| enemy.is_dead()) {
+            let mut object = self.objects.remove(enemy_id).unwrap();
+            self.collider.remove(&mut self.objects_in_area, &mut object);
+            self.game_data.score += config::ENEMY_SCORE;
+        }
+        self.enemies.retain(|(_, enemy)| !enemy.is_dead());
+    }
+
+    // Enemies close to the player look for a new path to it every now and then.
+    fn chase_player(&mut self, delta: f64) {
+        self.repath_timer -= delta;
+        if self.repath_timer > 0.0 {
+            return;
+        }
+        self.repath_timer = config::ENEMY_REPATH_INTERVAL;
+
+        let graph = match &self.nav_graph {
+            Some(graph) => graph,
+            None => return
+        };
+        let target = self.player_world_position();
+
+        for (enemy_id, enemy) in self.enemies.iter_mut() {
+            let object = &self.objects[enemy_id];
+            let distance = (object.position[0] - self.map.position[0] - target[0]).abs();
+            if distance < config::ENEMY_CHASE_RANGE {
+                enemy.move_to(graph, &self.map, object, target);
+            }
+        }
+    }
+
+    fn player_world_area(&self) -> [f64; 4] {
+        let position = self.player_world_position();
+        let half_size = self.objects[PLAYER_ID].aabb.half_size;
+        [position[0], position[1], half_size[0] * 2.0, half_size[1] * 2.0]
+    }
+
+    fn reached_exit(&self) -> bool {
+        let area = self.player_world_area();
+        self.exits.iter().any(|exit| exit.overlaps(area))
+    }
+
+    fn collect_pickups(&mut self) {
+        let area = self.player_world_area();
+
+        for pickup in self.pickups.iter_mut().filter(|pickup| !pickup.collected && pickup.overlaps(area)) {
+            pickup.collected = true;
+            self.game_data.score += pickup.score;
+        }
+    }
+
+    fn complete_level(&mut self) -> GameData {
+        let time = self.hud.elapsed_time();
+        let time_bonus = (config::TIME_BONUS - (time * config::TIME_BONUS_PER_SECOND) as i32).max(0);
+        self.speedrun.split();
+        if self.game_data.persist {
+            self.speedrun.finish();
+        }
+
+        self.game_data.score += time_bonus + self.game_data.lives * config::LIFE_BONUS;
+
+        let mut data = std::mem::replace(&mut self.game_data, GameData::new());
+        data.last_result = Some(LevelResult {
+            level: data.level.clone(),
+            score: data.score,
+            time
+        });
+        data.progress.checkpoint = None;
+        data
+    }
+
+    fn update_checkpoint(&mut self) {
+        let world_position = self.player_world_position();
+        let area = self.player_world_area();
+
+        let reached = self.checkpoints
+            .iter()
+            .rposition(|checkpoint| checkpoint.overlaps(area));
+
+        if reached.is_none() || reached <= self.last_checkpoint {
+            return;
+        }
+
+        let index = reached.unwrap();
+        self.last_checkpoint = reached;
+        self.speedrun.split();
+        self.game_data.progress.checkpoint = Some(Checkpoint {
+            level: self.game_data.level.clone(),
+            index,
+            position: world_position
+        });
+        self.game_data.save();
+    }
+
+    fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+
+        self.character.pressed_left = false;
+        self.character.pressed_right = false;
+        self.character.pressed_drop = false;
+        self.character.pressed_up = false;
+        self.character.pressed_jump = false;
+    }
+
+    fn select_pause_item(&mut self) {
+        match self.pause_menu.selected() {
+            RESUME => self.toggle_pause(),
+            QUIT_TO_TITLE => self.pause_action = Some(PauseAction::QuitToTitle),
+            QUIT_GAME => self.pause_action = Some(PauseAction::QuitGame),
+            _ => {}
+        }
+    }
+
+    // Springs and conveyors are drawn as blocks by the tile layers and ladders not at all, a tint
+    // tells them apart.
+    fn render_special_tiles(&self, ctx: &Context, gl: &mut GlGraphics, view: [f64; 4]) {
+        let tile_size = self.map.tile_size;
+        let first_row = (view[1] / tile_size).floor().max(0.0) as usize;
+        let first_column = (view[0] / tile_size).floor().max(0.0) as usize;
+        let rows = (view[3] / tile_size).ceil() as usize + 1;
+        let columns = (view[2] / tile_size).ceil() as usize + 1;
+
+        for (y, row) in self.map.tiles.iter().enumerate().skip(first_row).take(rows) {
+            for (x, tile) in row.iter().enumerate().skip(first_column).take(columns) {
+                let color = match tile {
+                    TileType::Spring => colors::SPRING,
+                    TileType::ConveyorLeft | TileType::ConveyorRight => colors::CONVEYOR,
+                    TileType::Ladder => colors::LADDER,
+                    _ => continue
+                };
+                let position = self.map.get_map_tile_position(x as i8, y as i8);
+                graphics::rectangle(color, [position[0], position[1], tile_size, tile_size], ctx.transform, gl);
+            }
+        }
+    }
+
+    fn render_pause_overlay(&mut self, ctx: &Context, gl: &mut GlGraphics, glyphs: &mut GlyphCache) {
+        use graphics::*;
+
+        let size = ctx.get_view_size();
+        rectangle(colors::OVERLAY, [0.0, 0.0, size[0], size[1]], ctx.transform, gl);
+
+        self.pause_menu.render(
+            ctx,
+            gl,
+            glyphs,
+            size[0] / 2.0 - config::MENU_FONT_SIZE as f64 * 3.0,
+            size[1] / 2.0 - config::MENU_FONT_SIZE as f64);
+    }
 }
 
 impl GameState for FirstLevel {
-    fn render(&mut self, ctx: &Context, mut gl: &mut GlGraphics, _glyphs: &mut GlyphCache) {
+    fn render(&mut self, ctx: &Context, mut gl: &mut GlGraphics, glyphs: &mut GlyphCache) {
         self.background.render(&ctx, &mut gl);
-        self.map.render(&ctx, &mut gl);
-        self.character.render(&ctx, &mut gl, &mut self.objects.get_mut("1ad31e1d-494a-41fe-bb9c-e7b8b83e59f1").unwrap());
-        self.enemy.render(&ctx, &mut gl, &mut self.objects.get_mut("5c8cd4c5-8d44-4326-bfa2-c803a30109fc").unwrap());
+        let view = self.camera.visible_rect(&self.map);
+        self.map_renderer.render(ctx, gl, &self.map, LayerKind::Background, view);
+        self.map_renderer.render(ctx, gl, &self.map, LayerKind::Collision, view);
+        self.render_special_tiles(ctx, gl, view);
+
+        if let Some(ghost) = self.speedrun.ghost_frame() {
+            let position = [ghost.position[0] + self.map.position[0], ghost.position[1]];
+            self.character.render_ghost(ctx, gl, ghost, position);
+        }
+
+        for pickup in &self.pickups {
+            pickup.render(ctx, gl, self.map.position);
+        }
+
+        self.character.render(&ctx, &mut gl, &mut self.objects.get_mut(PLAYER_ID).unwrap());
+        for (enemy_id, enemy) in self.enemies.iter_mut() {
+            enemy.render(ctx, gl, self.objects.get_mut(enemy_id).unwrap());
+        }
+        for projectile in self.projectiles.iter() {
+            projectile.render(ctx, gl, &self.objects[&projectile.object_id]);
+        }
+        self.map_renderer.render(ctx, gl, &self.map, LayerKind::Foreground, view);
+        self.debug_overlay.render_grid(ctx, gl, glyphs, &self.objects_in_area, &self.collider, &self.map);
+        self.debug_overlay.render_objects(ctx, gl, glyphs, &self.objects);
+    }
+
+    fn render_hud(&mut self, ctx: &Context, gl: &mut GlGraphics, glyphs: &mut GlyphCache) {
+        self.hud.render(ctx, gl, glyphs, &self.game_data);
+        self.speedrun.render(ctx, gl, glyphs);
+
+        if self.paused {
+            self.render_pause_overlay(ctx, gl, glyphs);
+        }
     }
 
     fn update(&mut self, args: &UpdateArgs) -> State<GameData> {
+        match self.pause_action.take() {
+            Some(PauseAction::QuitToTitle) => return State::Start(std::mem::replace(&mut self.game_data, GameData::new())),
+            Some(PauseAction::QuitGame) => return State::Quit,
+            None => {}
+        }
+
+        if self.open_editor {
+            return State::Editor(std::mem::replace(&mut self.game_data, GameData::new()));
+        }
+
+        if self.paused {
+            return State::None;
+        }
+
+        self.hud.update(args.dt);
+
         for object in self.objects.values_mut() {
             self.collider.update_areas(object, &self.map, &mut self.objects_in_area);
             object.all_colliding_objects.clear();
         }
         self.collider.check_collisions(&mut self.objects_in_area, &mut self.objects);
 
-        self.character.character_update(args.dt, &self.map, &mut self.objects.get_mut("1ad31e1d-494a-41fe-bb9c-e7b8b83e59f1").unwrap());
-        self.enemy.character_update(args.dt, &self.map, &mut self.objects.get_mut("5c8cd4c5-8d44-4326-bfa2-c803a30109fc").unwrap());
+        self.character.character_update(args.dt, &self.map, &mut self.objects.get_mut(PLAYER_ID).unwrap());
+        self.hit_enemies();
+        self.update_projectiles(args.dt);
+        self.chase_player(args.dt);
+        for (enemy_id, enemy) in self.enemies.iter_mut() {
+            enemy.character_update(args.dt, &self.map, self.nav_graph.as_ref(), self.objects.get_mut(enemy_id).unwrap());
+        }
         self.camera.update(&mut self.objects, &mut self.map, &mut self.character, &mut self.background, args.dt);
+        let ghost_frame = self.character.ghost_frame(self.player_world_position());
+        self.speedrun.tick(ghost_frame);
+        self.collect_pickups();
+        self.update_checkpoint();
+
+        if self.reached_exit() {
+            return State::End(self.complete_level());
+        }
         return State::None;
     }
 
+    fn summary(&self) -> String {
+        let player = &self.objects[PLAYER_ID];
+        let position = self.player_world_position();
+
+        format!(
+            "player position: [{:.1}, {:.1}]\nplayer speed: [{:.1}, {:.1}]\non ground: {}\nscore: {}\nlives: {}\ntime: {:.2}",
+            position[0],
+            position[1],
+            player.speed[0],
+            player.speed[1],
+            player.on_ground,
+            self.game_data.score,
+            self.game_data.lives,
+            self.hud.elapsed_time())
+    }
+
+    fn assets_changed(&mut self, changed: &[String]) {
+        let level = &self.game_data.level;
+        let level_file_changed = |extension: &str| changed.contains(&MapLoader::level_file(level, extension));
+
+        let objects_changed = level_file_changed("objects.yaml");
+        let level_changed = changed.contains(level) || level_file_changed("layers.yaml") || objects_changed;
+        let background_changed = level_file_changed("background.yaml");
+
+        if level_changed {
+            self.reload_level(objects_changed);
+        }
+        if background_changed {
+            self.reload_background();
+        }
+    }
+
     fn key_press(&mut self, args: &Button) {
+        if let Keyboard(Key::P) = *args {
+            self.toggle_pause();
+            return;
+        }
+
+        if let Keyboard(Key::F3) = *args {
+            self.debug_overlay.toggle();
+            self.game_data.debug_overlay = self.debug_overlay.enabled;
+            return;
+        }
+
+        if let Keyboard(Key::F2) = *args {
+            self.open_editor = self.game_data.dev_mode;
+            return;
+        }
+
+        if self.paused {
+            match self.pause_menu.handle_key(args) {
+                MenuInput::Select => self.select_pause_item(),
+                MenuInput::Back => self.toggle_pause(),
+                _ => {}
+            }
+            return;
+        }
+
         match *args {
+            Keyboard(Key::Escape) => self.toggle_pause(),
             Keyboard(Key::A) | Keyboard(Key::Left) => { self.character.pressed_left = true }
             Keyboard(Key::D) | Keyboard(Key::Right) => self.character.pressed_right = true,
             Keyboard(Key::S) | Keyboard(Key::Down) => self.character.pressed_drop = true,
+            Keyboard(Key::W) | Keyboard(Key::Up) => self.character.pressed_up = true,
             Keyboard(Key::Space) => self.character.pressed_jump = true,
+            Keyboard(Key::F) => self.shoot(),
+            Keyboard(Key::E) => self.character.pressed_attack = true,
             _ => {}
         }
     }
 
     fn key_release(&mut self, args: &Button) {
+        if self.paused {
+            return;
+        }
+
         match *args {
             Keyboard(Key::A) | Keyboard(Key::Left) => self.character.pressed_left = false,
             Keyboard(Key::D) | Keyboard(Key::Right) => self.character.pressed_right = false,
             Keyboard(Key::S) | Keyboard(Key::Down) => self.character.pressed_drop = false,
+            Keyboard(Key::W) | Keyboard(Key::Up) => self.character.pressed_up = false,
             Keyboard(Key::Space) => self.character.pressed_jump = false,
             _ => {}
         }
     }
-}
\ No newline at end of file
+}
+
+fn collider_size(tiles: i8) -> Result<i8, String> {
+    collider::padded_size(tiles as usize).ok_or_else(|| format!("{} tiles is more than the collider supports", tiles))
+}
@@ -1,13 +1,84 @@
+use super::config;
+use super::save_data::SaveData;
+
+pub struct LevelResult {
+    pub level: String,
+    pub score: i32,
+    pub time: f64
+}
+
 pub struct GameData {
     pub username: String,
-    pub score: i32
+    pub score: i32,
+    pub lives: i32,
+    pub show_fps: bool,
+    pub dev_mode: bool,
+    pub level: String,
+    pub debug_overlay: bool,
+    // Headless and replayed runs mustn't touch the player's save or best runs.
+    pub persist: bool,
+    pub progress: SaveData,
+    pub last_result: Option<LevelResult>
 }
 
 impl GameData{
     pub fn new() -> GameData{
         GameData{
             username: String::from("unknown"),
-            score: 0
+            score: 0,
+            lives: config::START_LIVES,
+            show_fps: true,
+            dev_mode: std::env::var_os(config::DEV_MODE_VAR).is_some(),
+            level: config::FIRST_LEVEL.to_string(),
+            debug_overlay: false,
+            persist: true,
+            progress: SaveData::new(),
+            last_result: None
+        }
+    }
+
+    pub fn load() -> GameData {
+        let progress = SaveData::load();
+
+        GameData {
+            username: progress.username.clone(),
+            progress,
+            ..GameData::new()
+        }
+    }
+
+    // Score and lives belong to a single run, they don't carry over into the next one.
+    pub fn reset_run(&mut self) {
+        self.score = 0;
+        self.lives = config::START_LIVES;
+        self.last_result = None;
+    }
+
+    pub fn save(&mut self) {
+        if !self.persist {
+            return;
         }
+
+        self.progress.username = self.username.clone();
+        self.progress.save();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reset_run_clears_the_previous_run() {
+        let mut data = GameData::new();
+        data.score = 1200;
+        data.lives = 1;
+        data.last_result = Some(LevelResult { level: "level.map".to_string(), score: 1200, time: 30.0 });
+
+        data.reset_run();
+
+        assert_eq!(data.score, 0);
+        assert_eq!(data.lives, config::START_LIVES);
+        assert!(data.last_result.is_none());
     }
-}
\ No newline at end of file
+}
@@ -7,7 +7,12 @@ use graphics::Context;
 
 pub trait GameState {
         fn render(&mut self, ctx: &Context, gl: &mut GlGraphics, glyphs: &mut GlyphCache);
+        fn render_hud(&mut self, _ctx: &Context, _gl: &mut GlGraphics, _glyphs: &mut GlyphCache) {}
         fn update(&mut self, args: &UpdateArgs) -> State<GameData>;
         fn key_press(&mut self, args: &Button);
         fn key_release(&mut self, args: &Button);
+        fn text_input(&mut self, _text: &str) {}
+        fn mouse_move(&mut self, _position: [f64; 2]) {}
+        fn summary(&self) -> String { String::new() }
+        fn assets_changed(&mut self, _changed: &[String]) {}
 }
\ No newline at end of file
@@ -0,0 +1,92 @@
+use opengl_graphics::{GlGraphics, GlyphCache};
+use graphics::Context;
+use std::time::Instant;
+
+use super::textwriter::TextWriter;
+use super::gamedata::GameData;
+use super::colors;
+use super::config;
+
+pub struct Hud {
+    text_writer: TextWriter,
+    elapsed_time: f64,
+    frames: u32,
+    fps: u32,
+    fps_timer: Instant
+}
+
+impl Hud {
+    pub fn new() -> Hud {
+        Hud {
+            text_writer: TextWriter::new(),
+            elapsed_time: 0.0,
+            frames: 0,
+            fps: 0,
+            fps_timer: Instant::now()
+        }
+    }
+
+    pub fn update(&mut self, delta: f64) {
+        self.elapsed_time += delta;
+    }
+
+    pub fn elapsed_time(&self) -> f64 {
+        self.elapsed_time
+    }
+
+    pub fn render(&mut self, ctx: &Context, gl: &mut GlGraphics, glyphs: &mut GlyphCache, data: &GameData) {
+        self.count_frame();
+
+        let size = ctx.get_view_size();
+        let margin = config::HUD_MARGIN;
+        let line_height = config::HUD_FONT_SIZE as f64 + margin / 2.0;
+
+        let lines = [
+            format!("Score: {}", data.score),
+            format!("Lives: {}", data.lives),
+            format!("Time: {}", format_time(self.elapsed_time))
+        ];
+
+        for (i, line) in lines.iter().enumerate() {
+            self.text_writer.render_text(
+                ctx,
+                gl,
+                glyphs,
+                colors::BLACK,
+                config::HUD_FONT_SIZE,
+                margin,
+                margin + line_height * (i + 1) as f64,
+                line);
+        }
+
+        if !data.show_fps {
+            return;
+        }
+
+        self.text_writer.render_text(
+            ctx,
+            gl,
+            glyphs,
+            colors::BLACK,
+            config::HUD_FONT_SIZE,
+            size[0] - margin - config::HUD_FONT_SIZE as f64 * 4.0,
+            margin + line_height,
+            &format!("FPS: {}", self.fps));
+    }
+
+    fn count_frame(&mut self) {
+        self.frames += 1;
+
+        let elapsed = self.fps_timer.elapsed().as_secs_f64();
+        if elapsed >= 1.0 {
+            self.fps = (self.frames as f64 / elapsed).round() as u32;
+            self.frames = 0;
+            self.fps_timer = Instant::now();
+        }
+    }
+}
+
+fn format_time(seconds: f64) -> String {
+    let minutes = (seconds / 60.0) as u32;
+    format!("{:02}:{:04.1}", minutes, seconds - minutes as f64 * 60.0)
+}
@@ -0,0 +1,120 @@
+use piston::input::Button;
+use serde::{Serialize, Deserialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Inputs are keyed by update tick, updates run at a fixed rate so replays are deterministic.
+#[derive(Copy, Clone, Serialize, Deserialize)]
+pub struct RecordedInput {
+    pub tick: u64,
+    pub pressed: bool,
+    pub button: Button
+}
+
+#[derive(Serialize, Deserialize)]
+struct Recording {
+    inputs: Vec<RecordedInput>
+}
+
+pub struct InputRecorder {
+    path: PathBuf,
+    inputs: Vec<RecordedInput>
+}
+
+impl InputRecorder {
+    pub fn new(path: &Path) -> InputRecorder {
+        InputRecorder {
+            path: path.to_path_buf(),
+            inputs: Vec::new()
+        }
+    }
+
+    pub fn record(&mut self, tick: u64, pressed: bool, button: Button) {
+        self.inputs.push(RecordedInput { tick, pressed, button });
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let content = serde_yaml::to_string(&Recording { inputs: self.inputs.clone() })
+            .map_err(|e| e.to_string())?;
+        fs::write(&self.path, content)
+            .map_err(|e| format!("can't write {}: {}", self.path.display(), e))
+    }
+}
+
+pub struct InputReplay {
+    inputs: Vec<RecordedInput>,
+    next: usize
+}
+
+impl InputReplay {
+    pub fn load(path: &Path) -> Result<InputReplay, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("can't read {}: {}", path.display(), e))?;
+        let recording: Recording = serde_yaml::from_str(&content)
+            .map_err(|e| format!("can't parse {}: {}", path.display(), e))?;
+
+        Ok(InputReplay {
+            inputs: recording.inputs,
+            next: 0
+        })
+    }
+
+    // Returns the inputs recorded before the given update tick that weren't replayed yet.
+    pub fn inputs_until(&mut self, tick: u64) -> &[RecordedInput] {
+        let start = self.next;
+        while self.next < self.inputs.len() && self.inputs[self.next].tick <= tick {
+            self.next += 1;
+        }
+        &self.inputs[start..self.next]
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next == self.inputs.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use piston::input::keyboard::Key;
+
+    #[test]
+    fn replays_what_was_recorded_tick_by_tick() {
+        let path = std::env::temp_dir().join(format!("rusty_platformer_recording_{}.yaml", std::process::id()));
+        let mut recorder = InputRecorder::new(&path);
+        recorder.record(0, true, Button::Keyboard(Key::D));
+        recorder.record(3, true, Button::Keyboard(Key::Space));
+        recorder.record(3, false, Button::Keyboard(Key::Space));
+        recorder.record(10, false, Button::Keyboard(Key::D));
+        recorder.save().unwrap();
+
+        let mut replay = InputReplay::load(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(replay.inputs_until(0).len(), 1);
+        assert!(!replay.is_finished());
+        assert!(replay.inputs_until(2).is_empty());
+
+        let jump = replay.inputs_until(3);
+        assert_eq!(jump.len(), 2);
+        assert!(jump[0].pressed && !jump[1].pressed);
+        assert_eq!(jump[0].button, Button::Keyboard(Key::Space));
+
+        let release = replay.inputs_until(100);
+        assert_eq!(release.len(), 1);
+        assert_eq!(release[0].tick, 10);
+        assert!(replay.is_finished());
+        assert!(replay.inputs_until(200).is_empty());
+    }
+
+    #[test]
+    fn missing_or_broken_recordings_are_errors() {
+        let path = std::env::temp_dir().join(format!("rusty_platformer_broken_{}.yaml", std::process::id()));
+        assert!(InputReplay::load(&path).is_err());
+
+        fs::write(&path, "inputs: 3").unwrap();
+        let result = InputReplay::load(&path);
+        let _ = fs::remove_file(&path);
+        assert!(result.is_err());
+    }
+}
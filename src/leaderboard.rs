@@ -0,0 +1,114 @@
+use opengl_graphics::{GlGraphics, GlyphCache};
+use piston::input::{UpdateArgs, Button};
+use piston::input::Button::Keyboard;
+use piston::input::keyboard::Key;
+use graphics::Context;
+
+use super::gamestate::GameState;
+use super::gamedata::GameData;
+use super::states::State;
+use super::textwriter::TextWriter;
+use super::colors;
+use super::config;
+
+pub struct Leaderboard {
+    level_index: usize,
+    closed: bool,
+    text_writer: TextWriter,
+    game_data: Option<GameData>
+}
+
+impl Leaderboard {
+    pub fn new(game_data: GameData) -> Leaderboard {
+        let level_index = game_data.last_result
+            .as_ref()
+            .and_then(|result| game_data.progress.unlocked_levels.iter().position(|level| *level == result.level))
+            .unwrap_or(0);
+
+        Leaderboard {
+            level_index,
+            closed: false,
+            text_writer: TextWriter::new(),
+            game_data: Some(game_data)
+        }
+    }
+
+    fn level_count(&self) -> usize {
+        self.game_data.as_ref().map_or(1, |data| data.progress.unlocked_levels.len().max(1))
+    }
+}
+
+impl GameState for Leaderboard {
+    fn render(&mut self, ctx: &Context, gl: &mut GlGraphics, glyphs: &mut GlyphCache) {
+        let data = match self.game_data.as_ref() {
+            Some(data) => data,
+            None => return
+        };
+
+        let level = data.progress.unlocked_levels
+            .get(self.level_index)
+            .map_or(config::FIRST_LEVEL, String::as_str);
+
+        let left = config::BOARD_SIZE_X as f64 / 4.0;
+        let top = config::BOARD_SIZE_Y as f64 / 6.0;
+        let line_height = config::HUD_FONT_SIZE as f64 * 1.5;
+
+        self.text_writer.render_text(
+            ctx,
+            gl,
+            glyphs,
+            colors::BLACK,
+            config::MENU_FONT_SIZE,
+            left,
+            top,
+            &format!("High Scores - {}", level));
+
+        let scores = data.progress.level_high_scores(level);
+        if scores.is_empty() {
+            self.text_writer.render_text(
+                ctx,
+                gl,
+                glyphs,
+                colors::DARK_GRAY,
+                config::HUD_FONT_SIZE,
+                left,
+                top + line_height * 2.0,
+                "No scores yet");
+        }
+
+        for (i, entry) in scores.iter().enumerate() {
+            let line = format!("{:>2}. {:<12} {:>6}  {:>7.1}s", i + 1, entry.name, entry.score, entry.time);
+            self.text_writer.render_text(
+                ctx,
+                gl,
+                glyphs,
+                colors::BLACK,
+                config::HUD_FONT_SIZE,
+                left,
+                top + line_height * (i + 2) as f64,
+                &line);
+        }
+    }
+
+    fn update(&mut self, _args: &UpdateArgs) -> State<GameData> {
+        if self.closed {
+            return State::Start(self.game_data.take().unwrap());
+        }
+        State::None
+    }
+
+    fn key_press(&mut self, args: &Button) {
+        match *args {
+            Keyboard(Key::A) | Keyboard(Key::Left) => {
+                self.level_index = (self.level_index + self.level_count() - 1) % self.level_count();
+            }
+            Keyboard(Key::D) | Keyboard(Key::Right) => {
+                self.level_index = (self.level_index + 1) % self.level_count();
+            }
+            Keyboard(Key::Return) | Keyboard(Key::Escape) | Keyboard(Key::Space) => self.closed = true,
+            _ => {}
+        }
+    }
+
+    fn key_release(&mut self, _args: &Button) {}
+}
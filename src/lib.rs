@@ -0,0 +1,40 @@
+pub mod gamestate;
+pub mod states;
+pub mod gamedata;
+pub mod colors;
+pub mod config;
+pub mod renderable;
+pub mod textwriter;
+pub mod hud;
+pub mod menu;
+pub mod title_screen;
+pub mod save_data;
+pub mod name_entry;
+pub mod leaderboard;
+pub mod speedrun;
+pub mod sprite_atlas;
+pub mod loading_screen;
+pub mod asset_watcher;
+pub mod AABB;
+pub mod animation_manager;
+pub mod moving_object;
+pub mod first_level;
+pub mod texture_loader;
+pub mod camera;
+pub mod background;
+pub mod character;
+pub mod animator;
+pub mod map;
+pub mod map_loader;
+pub mod tiled;
+pub mod pickup;
+pub mod projectile;
+pub mod trigger;
+pub mod map_renderer;
+pub mod collider;
+pub mod nav_graph;
+pub mod debug_overlay;
+pub mod editor;
+pub mod cli;
+pub mod input_recording;
+pub mod enemy;
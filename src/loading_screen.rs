@@ -0,0 +1,83 @@
+use opengl_graphics::{GlGraphics, GlyphCache};
+use piston::input::{UpdateArgs, Button};
+use graphics::Context;
+use std::rc::Rc;
+
+use super::gamestate::GameState;
+use super::gamedata::GameData;
+use super::states::State;
+use super::texture_loader::TextureLoader;
+use super::textwriter::TextWriter;
+use super::colors;
+use super::config;
+
+pub struct LoadingScreen {
+    texture_loader: Rc<TextureLoader>,
+    pending: Vec<String>,
+    total: usize,
+    text_writer: TextWriter,
+    game_data: Option<GameData>
+}
+
+impl LoadingScreen {
+    pub fn new(texture_loader: Rc<TextureLoader>, game_data: GameData) -> LoadingScreen {
+        let mut pending = texture_loader.load_manifest(config::ASSET_MANIFEST);
+        pending.reverse();
+        let total = pending.len();
+
+        LoadingScreen {
+            texture_loader,
+            pending,
+            total,
+            text_writer: TextWriter::new(),
+            game_data: Some(game_data)
+        }
+    }
+
+    fn progress(&self) -> f64 {
+        if self.total == 0 {
+            return 1.0;
+        }
+        (self.total - self.pending.len()) as f64 / self.total as f64
+    }
+}
+
+impl GameState for LoadingScreen {
+    fn render(&mut self, ctx: &Context, gl: &mut GlGraphics, glyphs: &mut GlyphCache) {
+        use graphics::*;
+
+        let width = config::BOARD_SIZE_X as f64 / 2.0;
+        let left = (config::BOARD_SIZE_X as f64 - width) / 2.0;
+        let top = config::BOARD_SIZE_Y as f64 / 2.0;
+
+        self.text_writer.render_text(
+            ctx,
+            gl,
+            glyphs,
+            colors::BLACK,
+            config::MENU_FONT_SIZE,
+            left,
+            top - config::MENU_FONT_SIZE as f64,
+            "Loading...");
+
+        rectangle(colors::DARK_GRAY, [left, top, width, config::LOADING_BAR_HEIGHT], ctx.transform, gl);
+        rectangle(colors::BLUE, [left, top, width * self.progress(), config::LOADING_BAR_HEIGHT], ctx.transform, gl);
+    }
+
+    fn update(&mut self, _args: &UpdateArgs) -> State<GameData> {
+        if self.pending.is_empty() {
+            return State::Game(self.game_data.take().unwrap());
+        }
+
+        for _ in 0..config::TEXTURES_PER_UPDATE {
+            if let Some(path) = self.pending.pop() {
+                self.texture_loader.load_texture(&path);
+            }
+        }
+        State::None
+    }
+
+    fn key_press(&mut self, _args: &Button) {}
+
+    fn key_release(&mut self, _args: &Button) {}
+}
@@ -12,61 +12,86 @@ use opengl_graphics::{OpenGL, GlGraphics, GlyphCache};
 use piston::event_loop::{EventSettings, Events};
 use piston::input::{RenderEvent, PressEvent, UpdateEvent};
 use piston::window::*;
+use piston::window::Window as _;
 use piston_window::*;
-use gamestate::GameState;
-use map::Map;
-use map_loader::MapLoader;
-use texture_loader::TextureLoader;
-use states::State;
+use rusty_platformer::{config, colors, title_screen, loading_screen, first_level, name_entry, leaderboard, asset_watcher, editor};
+use rusty_platformer::cli::{self, Options};
+use rusty_platformer::gamedata::GameData;
+use rusty_platformer::input_recording::{InputRecorder, InputReplay};
+use rusty_platformer::gamestate::GameState;
+use rusty_platformer::map_loader::MapLoader;
+use rusty_platformer::texture_loader::TextureLoader;
+use rusty_platformer::states::State;
 use std::rc::Rc;
 use std::cmp;
 use std::cell::RefCell;
 use std::path::PathBuf;
 
-mod gamestate;
-mod states;
-mod gamedata;
-mod colors;
-mod config;
-mod renderable;
-mod textwriter;
-mod AABB;
-mod animation_manager;
-mod moving_object;
-mod first_level;
-mod texture_loader;
-mod camera;
-mod background;
-mod character;
-mod animator;
-mod map;
-mod map_loader;
-mod collider;
-mod enemy;
-
 fn main() {
+    let options = Options::parse(std::env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("{}\n\n{}", e, cli::USAGE);
+        std::process::exit(2);
+    });
+
+    if options.help {
+        println!("{}", cli::USAGE);
+        return;
+    }
+
+    let assets = Rc::new(match &options.assets {
+        Some(assets) => assets.clone(),
+        None => find_folder::Search::ParentsThenKids(3, 3)
+                .for_folder("assets")
+                .unwrap()
+    });
+
+    let mut replay = options.replay.as_ref().map(|path| InputReplay::load(path).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }));
+    let mut recorder = options.record.as_ref().map(|path| InputRecorder::new(path));
+
+    if let Some(ticks) = options.headless {
+        run_headless(&options, assets, ticks, replay);
+        return;
+    }
+
     let opengl = OpenGL::V3_2;
 
-    let mut window: Window = WindowSettings::new("Rusty Platformer", [1120, 800])
+    let mut window: Window = WindowSettings::new("Rusty Platformer", options.window_size)
         .graphics_api(OpenGL::V3_2)
-        .exit_on_esc(true)
+        .fullscreen(options.fullscreen)
+        .exit_on_esc(false)
         .build()
         .unwrap();
 
-    let assets = Rc::new(find_folder::Search::ParentsThenKids(3, 3)
-                .for_folder("assets")
-                .unwrap());
-    
     let texture_loader = Rc::new(TextureLoader::new(Rc::clone(&assets)));
     let map_loader = Rc::new(MapLoader::new(Rc::clone(&assets)));
 
-    let mut current_state: Box<dyn GameState> = Box::new(first_level::FirstLevel::new(Rc::clone(&texture_loader), Rc::clone(&map_loader)));
+    let mut asset_watcher = if std::env::var_os(config::DEV_MODE_VAR).is_some() {
+        Some(asset_watcher::AssetWatcher::new(Rc::clone(&assets)))
+    } else {
+        None
+    };
+
+    let mut game_data = GameData::load();
+    options.apply(&mut game_data);
+
+    let mut current_state: Box<dyn GameState> = if options.starts_in_level() {
+        Box::new(loading_screen::LoadingScreen::new(Rc::clone(&texture_loader), game_data))
+    } else {
+        Box::new(title_screen::TitleScreen::new(game_data))
+    };
+    // Recorded ticks count level updates only, like `run_headless`, so the loading screen
+    // taking longer or shorter doesn't shift inputs.
+    let mut level_tick: Option<u64> = None;
 
     let mut events = get_events_loop();
     let mut glyph_cache = get_font(Rc::clone(&assets));
 
     let mut gl = GlGraphics::new(opengl);
     let (ax, ay) = config::TARGET_ASPECT;
+    let mut viewport = (config::BOARD_SIZE_X as f64, config::BOARD_SIZE_Y as f64, 0.0, 0.0);
 
     while let Some(e) = events.next(&mut window){
         if let Some(args) = e.render_args(){
@@ -75,38 +100,151 @@ fn main() {
                 clear(colors::GRAY, gl);
 
                 let (width,height, left, bottom) = calculate_viewport(&c);
+                viewport = (width, height, left, bottom);
 
-                let c = c.scale(
+                let board = c.scale(
                     width / config::BOARD_SIZE_X as f64,
                     height / config::BOARD_SIZE_Y as f64);
-                let c = c.trans(left, bottom);
+                let board = board.trans(left, bottom);
 
-                current_state.render(&c, &mut gl, &mut glyph_cache);
+                current_state.render(&board, &mut gl, &mut glyph_cache);
+                current_state.render_hud(&c, &mut gl, &mut glyph_cache);
             });
         }
 
         if let Some(args) = e.update_args(){
+            if let Some(watcher) = asset_watcher.as_mut() {
+                let changed = watcher.poll(args.dt);
+                if !changed.is_empty() {
+                    texture_loader.reload(&changed);
+                    current_state.assets_changed(&changed);
+                }
+            }
+
+            if let Some(tick) = level_tick.as_mut() {
+                if let Some(replay) = replay.as_mut() {
+                    for input in replay.inputs_until(*tick) {
+                        if input.pressed {
+                            current_state.key_press(&input.button);
+                        } else {
+                            current_state.key_release(&input.button);
+                        }
+                    }
+                }
+                *tick += 1;
+            }
+
             let state_finished = current_state.update(&args);
+            let state_changed = !matches!(state_finished, State::None | State::Quit);
+            if level_tick.is_none() && matches!(state_finished, State::Game(_)) {
+                level_tick = Some(0);
+            }
             
             current_state = 
             match state_finished {
-                State::Start(data) => {current_state},
-                State::Game(data) => {current_state},
-                State::End(data) => {current_state},
+                State::Start(data) => Box::new(title_screen::TitleScreen::new(data)),
+                State::Loading(data) => Box::new(loading_screen::LoadingScreen::new(Rc::clone(&texture_loader), data)),
+                State::Game(data) => Box::new(start_level(Rc::clone(&texture_loader), Rc::clone(&map_loader), data)),
+                State::End(data) => Box::new(name_entry::NameEntry::new(data)),
+                State::Leaderboard(data) => Box::new(leaderboard::Leaderboard::new(data)),
+                State::Editor(data) => Box::new(editor::Editor::new(Rc::clone(&texture_loader), Rc::clone(&map_loader), data)),
+                State::Quit => {
+                    window.set_should_close(true);
+                    current_state
+                },
                 State::None => {current_state},
+            };
+
+            if state_changed {
+                texture_loader.evict_unused();
+            }
+        }
+
+        // Live input would change the replayed run, it's only taken once the replay ran out so
+        // the name entry and menus after the level still work.
+        if replay.as_ref().is_none_or(|replay| replay.is_finished()) {
+            if let Some(args) = e.press_args(){
+                if let (Some(recorder), Some(tick)) = (recorder.as_mut(), level_tick) {
+                    recorder.record(tick, true, args);
+                }
+                current_state.key_press(&args);
+            }
+
+            if let Some(args) = e.release_args(){
+                if let (Some(recorder), Some(tick)) = (recorder.as_mut(), level_tick) {
+                    recorder.record(tick, false, args);
+                }
+                current_state.key_release(&args);
             }
         }
 
-        if let Some(args) = e.press_args(){
-            current_state.key_press(&args);
+        if let Some(text) = e.text_args(){
+            current_state.text_input(&text);
         }
 
-        if let Some(args) = e.release_args(){
-            current_state.key_release(&args);
+        if let Some(position) = e.mouse_cursor_args(){
+            let (width, height, left, bottom) = viewport;
+            current_state.mouse_move([
+                position[0] * config::BOARD_SIZE_X as f64 / width - left,
+                position[1] * config::BOARD_SIZE_Y as f64 / height - bottom
+            ]);
+        }
+    }
+
+    if let Some(recorder) = recorder {
+        if let Err(error) = recorder.save() {
+            eprintln!("{}", error);
         }
     }
 }
 
+// Runs the level without a window at the fixed update rate and prints where it ended up.
+fn run_headless(options: &Options, assets: Rc<PathBuf>, ticks: u64, mut replay: Option<InputReplay>) {
+    let texture_loader = Rc::new(TextureLoader::headless(Rc::clone(&assets)));
+    let map_loader = Rc::new(MapLoader::new(Rc::clone(&assets)));
+
+    let mut game_data = GameData::new();
+    options.apply(&mut game_data);
+
+    let mut level: Box<dyn GameState> = Box::new(start_level(texture_loader, map_loader, game_data));
+    let args = UpdateArgs { dt: 1.0 / config::UPS as f64 };
+
+    let mut outcome = "running".to_string();
+    let mut tick: u64 = 0;
+
+    while tick < ticks {
+        if let Some(replay) = replay.as_mut() {
+            for input in replay.inputs_until(tick) {
+                if input.pressed {
+                    level.key_press(&input.button);
+                } else {
+                    level.key_release(&input.button);
+                }
+            }
+        }
+        tick += 1;
+
+        match level.update(&args) {
+            State::None => continue,
+            State::End(data) => {
+                let result = data.last_result.unwrap();
+                outcome = format!("level completed, score {}, time {:.2}", result.score, result.time);
+            },
+            _ => outcome = "left the level".to_string()
+        }
+        break;
+    }
+
+    println!("ticks: {}\noutcome: {}\n{}", tick, outcome, level.summary());
+}
+
+fn start_level(texture_loader: Rc<TextureLoader>, map_loader: Rc<MapLoader>, game_data: GameData) -> first_level::FirstLevel {
+    first_level::FirstLevel::new(texture_loader, map_loader, game_data).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    })
+}
+
 fn get_font(assets_path: Rc<PathBuf>) -> GlyphCache<'static> {
     let font_path = assets_path.join("AllertaStencil-Regular.ttf");
     GlyphCache::new(&font_path, (), TextureSettings::new()).unwrap()
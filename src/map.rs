@@ -1,21 +1,56 @@
 use graphics::math::*;
 use super::config;
 use super::camera::CameraDependentObject;
-use super::renderable::Renderable;
 use super::colors;
 use super::animator;
-use super::texture_loader::TextureLoader;
-use opengl_graphics::Texture;
-use opengl_graphics::GlGraphics;
-use graphics::Context;
-use std::rc::Rc;
+use serde::Deserialize;
 
 
 #[derive(Copy, Clone, PartialEq)]
 pub enum TileType {
     Empty,
     Block,
-    OneWay
+    OneWay,
+    // Solid tiles that launch whatever lands on them or move whatever stands on them.
+    Spring,
+    ConveyorLeft,
+    ConveyorRight,
+    // Climbable, the top of a ladder can be stood on like a one way platform.
+    Ladder
+}
+
+impl TileType {
+    pub fn from_char(c: char) -> Option<TileType> {
+        match c {
+            'E' => Some(TileType::Empty),
+            'B' => Some(TileType::Block),
+            'O' => Some(TileType::OneWay),
+            'S' => Some(TileType::Spring),
+            '<' => Some(TileType::ConveyorLeft),
+            '>' => Some(TileType::ConveyorRight),
+            'H' => Some(TileType::Ladder),
+            _ => None
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            TileType::Empty => 'E',
+            TileType::Block => 'B',
+            TileType::OneWay => 'O',
+            TileType::Spring => 'S',
+            TileType::ConveyorLeft => '<',
+            TileType::ConveyorRight => '>',
+            TileType::Ladder => 'H'
+        }
+    }
+
+    pub fn is_solid(self) -> bool {
+        match self {
+            TileType::Block | TileType::Spring | TileType::ConveyorLeft | TileType::ConveyorRight => true,
+            TileType::Empty | TileType::OneWay | TileType::Ladder => false
+        }
+    }
 }
 
 #[derive(Eq, PartialEq, Hash, Clone)]
@@ -24,32 +59,46 @@ pub struct AreaIndex {
     pub y: i8
 }
 
+#[derive(Copy, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LayerKind {
+    Background,
+    Collision,
+    Foreground
+}
+
+// A tileset without a tile size uses the whole image as its only tile.
+pub struct Tileset {
+    pub image: String,
+    pub first_gid: u32,
+    pub tile_width: Option<f64>,
+    pub tile_height: Option<f64>,
+    pub margin: f64,
+    pub spacing: f64
+}
+
+// Tiles are global tile ids indexed as [row][column], 0 means no tile.
+pub struct TileLayer {
+    pub name: String,
+    pub kind: LayerKind,
+    pub tiles: Vec<Vec<u32>>
+}
+
 pub struct Map {
     pub tiles: Vec<Vec<TileType>>,
     pub position: Vec2d,
     width: i8,
     height: i8,
     pub tile_size: f64,
-    tile_texture: Texture,
-    one_way_texture: Texture,
-    tile_tex_scale: f64
+    pub tilesets: Vec<Tileset>,
+    pub layers: Vec<TileLayer>,
+    layers_from_collision: bool
 }
 
 impl Map {
 
-   pub fn new(tiles: Vec<Vec<TileType>>, position: Vec2d, width: i8, height: i8, tile_size: f64, texture_loader: Rc<TextureLoader>) -> Map {
-        use graphics::*;
-
-        let texture = texture_loader.load_texture("Tiles/crate-std-2.png");
-        let ow_texture = texture_loader.load_texture("Tiles/grid-line-1.png");
-
-        let texture_size = texture.get_size();
-
-        if texture_size.0 != texture_size.1 {
-            panic!("Tile texture must be square");
-        }
-
-        let tile_tex_scale = tile_size / texture_size.0 as f64;
+   pub fn new(tiles: Vec<Vec<TileType>>, position: Vec2d, width: i8, height: i8, tile_size: f64) -> Map {
+        let layers = vec![Map::collision_layer(&tiles)];
 
         Map {
             tiles: tiles,
@@ -57,9 +106,90 @@ impl Map {
             width: width,
             height: height,
             tile_size,
-            tile_texture: texture,
-            one_way_texture: ow_texture,
-            tile_tex_scale: tile_tex_scale
+            tilesets: Map::collision_tilesets(),
+            layers,
+            layers_from_collision: true
+        }
+   }
+
+   pub fn set_layers(&mut self, tilesets: Vec<Tileset>, layers: Vec<TileLayer>) {
+        self.tilesets = tilesets;
+        self.layers = layers;
+        self.layers_from_collision = false;
+   }
+
+   // Goes back to drawing the collision tiles, like a map without layers of its own.
+   pub fn use_collision_layers(&mut self) {
+        self.tilesets = Map::collision_tilesets();
+        self.layers = vec![Map::collision_layer(&self.tiles)];
+        self.layers_from_collision = true;
+   }
+
+   pub fn replace_tiles(&mut self, tiles: Vec<Vec<TileType>>) -> Result<(), String> {
+        if tiles.len() != self.tiles.len() || tiles.iter().zip(self.tiles.iter()).any(|(new, old)| new.len() != old.len()) {
+            return Err("map dimensions changed".to_string());
+        }
+
+        if self.layers_from_collision {
+            self.layers = vec![Map::collision_layer(&tiles)];
+        }
+        self.tiles = tiles;
+        Ok(())
+   }
+
+   pub fn set_tile(&mut self, x: usize, y: usize, tile: TileType) {
+        self.tiles[y][x] = tile;
+        if self.layers_from_collision {
+            self.layers[0].tiles[y][x] = Map::collision_gid(tile);
+        }
+   }
+
+   pub fn tileset_index(&self, gid: u32) -> Option<usize> {
+        self.tilesets
+            .iter()
+            .enumerate()
+            .filter(|(_, tileset)| tileset.first_gid <= gid)
+            .max_by_key(|(_, tileset)| tileset.first_gid)
+            .map(|(i, _)| i)
+   }
+
+   fn collision_tilesets() -> Vec<Tileset> {
+        ["Tiles/crate-std-2.png", "Tiles/grid-line-1.png"]
+            .iter()
+            .enumerate()
+            .map(|(i, image)| Tileset {
+                image: image.to_string(),
+                first_gid: i as u32 + 1,
+                tile_width: None,
+                tile_height: None,
+                margin: 0.0,
+                spacing: 0.0
+            })
+            .collect()
+   }
+
+   fn collision_layer(tiles: &[Vec<TileType>]) -> TileLayer {
+        let tiles = tiles
+            .iter()
+            .map(|row| row.iter().map(|tile| Map::collision_gid(*tile)).collect())
+            .collect();
+
+        TileLayer {
+            name: "collision".to_string(),
+            kind: LayerKind::Collision,
+            tiles
+        }
+   }
+
+   pub fn size(&self) -> Vec2d {
+        [self.width as f64 * self.tile_size, self.height as f64 * self.tile_size]
+   }
+
+   fn collision_gid(tile: TileType) -> u32 {
+        match tile {
+            TileType::Block | TileType::Spring | TileType::ConveyorLeft | TileType::ConveyorRight => 1,
+            TileType::OneWay => 2,
+            TileType::Empty | TileType::Ladder => 0
         }
    }
 
@@ -93,29 +223,37 @@ impl Map {
        if x < 0 || x >= self.width || y < 0 || y >= self.height {
            return TileType::Block;
        }
-       return self.tiles[x as usize][y as usize];
+       return self.tiles[y as usize][x as usize];
    }
 
    pub fn is_obstacle(&self, x: i8, y: i8) -> bool {
     if x < 0 || x >= self.width || y < 0 || y >= self.height {
         return true;
     }
-    return self.tiles[y as usize][x as usize] == TileType::Block;
+    return self.tiles[y as usize][x as usize].is_solid();
    }
 
    pub fn is_ground(&self, x: i8, y: i8) -> bool {
     if x < 0 || x >= self.width || y < 0 || y >= self.height {
         return false;
     }
-    return self.tiles[y as usize][x as usize] == TileType::OneWay
-        || self.tiles[y as usize][x as usize] == TileType::Block;
+    return self.is_one_way_platform(x, y)
+        || self.tiles[y as usize][x as usize].is_solid();
    }
 
    pub fn is_one_way_platform(&self, x: i8, y: i8) -> bool {
     if x < 0 || x >= self.width || y < 0 || y >= self.height {
         return false;
     }
-    return self.tiles[y as usize][x as usize] == TileType::OneWay;
+    return self.tiles[y as usize][x as usize] == TileType::OneWay
+        || (self.is_ladder(x, y) && !self.is_ladder(x, y - 1));
+   }
+
+   pub fn is_ladder(&self, x: i8, y: i8) -> bool {
+    if x < 0 || x >= self.width || y < 0 || y >= self.height {
+        return false;
+    }
+    self.tiles[y as usize][x as usize] == TileType::Ladder
    }
 
    pub fn is_empty(&self, x: i8, y: i8) -> bool {
@@ -124,36 +262,6 @@ impl Map {
     }
     return self.tiles[y as usize][x as usize] == TileType::Empty;
    }
-
-   pub fn render_tile(&self, ctx: &Context, gl: &mut GlGraphics, tile_texture: &Texture, tile_index: (usize, usize)) {
-    use graphics::*;
-
-    let y = self.tile_size * tile_index.0 as f64 + self.position[1];
-    let x = self.tile_size * tile_index.1 as f64 + self.position[0];
-
-    let point_trans = ctx.transform
-        .trans(x, y)
-        .scale(self.tile_tex_scale, self.tile_tex_scale);
-
-    image(tile_texture, point_trans, gl);
-   }
-}
-
-impl Renderable for Map {
-    fn render(&mut self, ctx: &Context, gl: &mut GlGraphics) {
-
-        for (i, columns) in self.tiles.iter().enumerate() {
-            for (k, tile) in columns.iter().enumerate() {
-                if *tile == TileType::Block {
-                    self.render_tile(&ctx, gl, &self.tile_texture, (i, k));
-                }
-
-                if *tile == TileType::OneWay {
-                    self.render_tile(&ctx, gl, &self.one_way_texture, (i, k));
-                }
-            }
-        }
-    }
 }
 
 impl CameraDependentObject for Map {
@@ -1,8 +1,152 @@
-use super::map::TileType;
-use std::path::PathBuf;
+use super::map::{TileType, Tileset, TileLayer, LayerKind};
+use super::tiled;
+use super::background::LayerConfig;
+use graphics::math::Vec2d;
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
-use std::fs::File;
-use std::io::prelude::Read;
+
+// Objects added to levels that don't place their own.
+const PLAYER_SPAWN: [f64; 2] = [50.0, 300.0];
+const ENEMY_SPAWN: [f64; 2] = [300.0, 300.0];
+const CHECKPOINT_COLUMNS: [usize; 3] = [20, 45, 70];
+const EXIT_COLUMN: usize = 90;
+
+#[derive(Deserialize)]
+struct BackgroundFile {
+    layers: Vec<LayerConfig>
+}
+
+#[derive(Deserialize)]
+struct LayerFile {
+    tilesets: Vec<TilesetEntry>,
+    layers: Vec<LayerEntry>
+}
+
+#[derive(Deserialize)]
+struct TilesetEntry {
+    image: String,
+    first_gid: u32,
+    tile_width: Option<f64>,
+    tile_height: Option<f64>,
+    #[serde(default)]
+    margin: f64,
+    #[serde(default)]
+    spacing: f64
+}
+
+#[derive(Deserialize)]
+struct LayerEntry {
+    name: String,
+    kind: LayerKind,
+    rows: Vec<String>
+}
+
+#[derive(Serialize, Deserialize)]
+struct ObjectFile {
+    objects: Vec<LevelObject>
+}
+
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ObjectKind {
+    Spawn,
+    Enemy,
+    Pickup,
+    Trigger
+}
+
+// Positions and sizes are in world pixels.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct LevelObject {
+    pub kind: ObjectKind,
+    #[serde(default)]
+    pub name: String,
+    pub position: Vec2d,
+    #[serde(default)]
+    pub size: Vec2d,
+    #[serde(default)]
+    pub properties: HashMap<String, String>
+}
+
+impl LevelObject {
+    pub fn new(kind: ObjectKind, position: Vec2d, size: Vec2d) -> LevelObject {
+        LevelObject {
+            kind,
+            name: String::new(),
+            position,
+            size,
+            properties: HashMap::new()
+        }
+    }
+
+    fn trigger(area: [f64; 4], action: &str) -> LevelObject {
+        let mut trigger = LevelObject::new(ObjectKind::Trigger, [area[0], area[1]], [area[2], area[3]]);
+        trigger.properties.insert("action".to_string(), action.to_string());
+        trigger
+    }
+}
+
+// Tilesets and the visual layers drawn with them.
+pub type LevelLayers = (Vec<Tileset>, Vec<TileLayer>);
+
+pub struct Level {
+    pub tiles: Vec<Vec<TileType>>,
+    pub layers: Option<LevelLayers>,
+    pub objects: Vec<LevelObject>
+}
+
+impl Level {
+    pub fn width(&self) -> usize {
+        self.tiles.first().map_or(0, |row| row.len())
+    }
+
+    pub fn height(&self) -> usize {
+        self.tiles.len()
+    }
+
+    // Width and height as `Map` stores them, `load_level` only returns levels that fit.
+    pub fn size(&self) -> Result<(i8, i8), String> {
+        use std::convert::TryFrom;
+
+        match (i8::try_from(self.width()), i8::try_from(self.height())) {
+            (Ok(width), Ok(height)) => Ok((width, height)),
+            _ => Err(format!("{}x{} tiles is larger than maps can be, at most {} tiles wide and high", self.width(), self.height(), i8::MAX))
+        }
+    }
+
+    pub fn objects_of(&self, kind: ObjectKind) -> impl Iterator<Item = &LevelObject> {
+        self.objects.iter().filter(move |object| object.kind == kind)
+    }
+
+    // Gives plain maps without an objects file the original spawns, checkpoints and exit.
+    pub fn add_default_objects(&mut self, tile_size: f64) {
+        let has_objects = !self.objects.is_empty();
+        let (width, height) = (self.width(), self.height());
+        let level_area = |column: usize| [
+            column as f64 * tile_size,
+            0.0,
+            width.saturating_sub(column) as f64 * tile_size,
+            height as f64 * tile_size
+        ];
+
+        if self.objects_of(ObjectKind::Spawn).next().is_none() {
+            self.objects.push(LevelObject::new(ObjectKind::Spawn, PLAYER_SPAWN, [0.0, 0.0]));
+        }
+
+        if !has_objects {
+            self.objects.push(LevelObject::new(ObjectKind::Enemy, ENEMY_SPAWN, [0.0, 0.0]));
+        }
+
+        if self.objects_of(ObjectKind::Trigger).next().is_none() {
+            for column in CHECKPOINT_COLUMNS.iter() {
+                self.objects.push(LevelObject::trigger(level_area(*column), "checkpoint"));
+            }
+            self.objects.push(LevelObject::trigger(level_area(EXIT_COLUMN), "exit"));
+        }
+    }
+}
 
 pub struct MapLoader {
     assets_path: Rc<PathBuf>
@@ -15,26 +159,237 @@ impl MapLoader {
         }
     }
 
-    pub fn load_map(&self, path: &str) -> Vec<Vec<TileType>> {
+    // Tiled maps (.json or .tmx) are imported, anything else is read as a plain map.
+    pub fn load_level(&self, path: &str, tile_size: f64) -> Result<Level, String> {
+        let read = |asset: &str| std::fs::read_to_string(self.assets_path.join(asset))
+            .map_err(|e| format!("can't read {}: {}", asset, e));
+
+        let level = match Path::new(path).extension().and_then(|extension| extension.to_str()) {
+            Some("json") => tiled::import_json(path, &read(path)?, tile_size, &read)?,
+            Some("tmx") => tiled::import_tmx(path, &read(path)?, tile_size, &read)?,
+            _ => Level {
+                tiles: self.load_map(path)?,
+                layers: self.load_layers(path)?,
+                objects: self.load_objects(path)?
+            }
+        };
+
+        level.size()?;
+        Ok(level)
+    }
+
+    // A file next to the map, e.g. "level.objects.yaml" for "level.map" and "objects.yaml".
+    pub fn level_file(path: &str, extension: &str) -> String {
+        Path::new(path).with_extension(extension).to_string_lossy().replace('\\', "/")
+    }
+
+    // Only plain maps can be written back, Tiled maps are edited in Tiled.
+    pub fn is_editable(path: &str) -> bool {
+        !matches!(Path::new(path).extension().and_then(|extension| extension.to_str()), Some("json") | Some("tmx"))
+    }
+
+    pub fn save_map(&self, path: &str, tiles: &[Vec<TileType>], objects: &[LevelObject]) -> Result<(), String> {
+        let content: String = tiles
+            .iter()
+            .map(|row| {
+                let mut line: String = row.iter().map(|tile| tile.to_char()).collect();
+                line.push('\n');
+                line
+            })
+            .collect();
+
+        let objects = serde_yaml::to_string(&ObjectFile { objects: objects.to_vec() })
+            .map_err(|e| e.to_string())?;
+
         let map_path = self.assets_path.join(path);
-        let mut file = File::open(map_path).expect("can't open file");
-        let mut content = String::new();
-        file.read_to_string(&mut content).expect("can't read file");
+        std::fs::write(&map_path, content)
+            .map_err(|e| format!("can't write {}: {}", map_path.display(), e))?;
+
+        let objects_path = map_path.with_extension("objects.yaml");
+        std::fs::write(&objects_path, objects)
+            .map_err(|e| format!("can't write {}: {}", objects_path.display(), e))
+    }
+
+    // Objects of plain maps live next to the map, e.g. "level.map" uses "level.objects.yaml".
+    fn load_objects(&self, map_path: &str) -> Result<Vec<LevelObject>, String> {
+        let objects_path = self.assets_path.join(map_path).with_extension("objects.yaml");
+        if !objects_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = std::fs::read_to_string(&objects_path)
+            .map_err(|e| format!("can't read {}: {}", objects_path.display(), e))?;
+        let file: ObjectFile = serde_yaml::from_str(&content)
+            .map_err(|e| format!("can't parse {}: {}", objects_path.display(), e))?;
+        Ok(file.objects)
+    }
+
+    // The map as written, `load_map` skips characters that aren't tiles.
+    pub fn read_map(&self, path: &str) -> Result<String, String> {
+        let map_path = self.assets_path.join(path);
+        std::fs::read_to_string(&map_path)
+            .map_err(|e| format!("can't read {}: {}", map_path.display(), e))
+    }
+
+    pub fn load_map(&self, path: &str) -> Result<Vec<Vec<TileType>>, String> {
+        let content = self.read_map(path)?;
         let mut map = Vec::new();
 
         for lines in content.lines() {
             let mut row = Vec::new();
             for c in lines.chars() {
-                if c == 'E' {
-                 row.push(TileType::Empty);   
-                } else if c == 'O' {
-                    row.push(TileType::OneWay);
-                } else if c == 'B' {
-                    row.push(TileType::Block);
+                if let Some(tile) = TileType::from_char(c) {
+                    row.push(tile);
                 }
             }
             map.push(row);
         }
-        map
+        Ok(map)
+    }
+
+    // Visual layers live next to the map, e.g. "level.map" uses "level.layers.yaml".
+    pub fn load_layers(&self, map_path: &str) -> Result<Option<LevelLayers>, String> {
+        let layers_path = self.assets_path.join(map_path).with_extension("layers.yaml");
+        if !layers_path.exists() {
+            return Ok(None);
+        }
+
+        let content = std::fs::read_to_string(&layers_path)
+            .map_err(|e| format!("can't read {}: {}", layers_path.display(), e))?;
+        let file: LayerFile = serde_yaml::from_str(&content)
+            .map_err(|e| format!("can't parse {}: {}", layers_path.display(), e))?;
+
+        let tilesets = file.tilesets
+            .into_iter()
+            .map(|entry| Tileset {
+                image: entry.image,
+                first_gid: entry.first_gid,
+                tile_width: entry.tile_width,
+                tile_height: entry.tile_height,
+                margin: entry.margin,
+                spacing: entry.spacing
+            })
+            .collect();
+
+        let mut layers = Vec::new();
+        for entry in file.layers {
+            let tiles = entry.rows
+                .iter()
+                .map(|row| row
+                    .split_whitespace()
+                    .map(|gid| gid.parse().map_err(|_| format!("{}: invalid tile id {} in layer {}", layers_path.display(), gid, entry.name)))
+                    .collect::<Result<Vec<u32>, String>>())
+                .collect::<Result<Vec<Vec<u32>>, String>>()?;
+
+            layers.push(TileLayer { name: entry.name, kind: entry.kind, tiles });
+        }
+
+        Ok(Some((tilesets, layers)))
+    }
+
+    // Parallax layers live next to the map too, e.g. "level.map" uses "level.background.yaml".
+    pub fn load_background(&self, map_path: &str) -> Result<Vec<LayerConfig>, String> {
+        let background_path = self.assets_path.join(map_path).with_extension("background.yaml");
+        if !background_path.exists() {
+            return Ok(LayerConfig::defaults());
+        }
+
+        let content = std::fs::read_to_string(&background_path)
+            .map_err(|e| format!("can't read {}: {}", background_path.display(), e))?;
+        let file: BackgroundFile = serde_yaml::from_str(&content)
+            .map_err(|e| format!("can't parse {}: {}", background_path.display(), e))?;
+        Ok(file.layers)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assets(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rusty_platformer_maps_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("level.map"), "EEEE\nBOHB\n").unwrap();
+        dir
+    }
+
+    fn load(dir: &Path) -> Result<Level, String> {
+        MapLoader::new(Rc::new(dir.to_path_buf())).load_level("level.map", 24.0)
+    }
+
+    fn load_error(dir: &Path) -> String {
+        match load(dir) {
+            Ok(_) => panic!("loaded a broken level from {}", dir.display()),
+            Err(error) => error
+        }
+    }
+
+    #[test]
+    fn loads_plain_maps_with_their_layers() {
+        let dir = assets("plain");
+        std::fs::write(dir.join("level.layers.yaml"), "
+tilesets:
+  - image: Tiles/tiles.png
+    first_gid: 1
+layers:
+  - name: ground
+    kind: foreground
+    rows:
+      - 0 0 0 0
+      - 1 2 2 1
+").unwrap();
+
+        let level = load(&dir).unwrap();
+
+        assert_eq!(level.width(), 4);
+        assert_eq!(level.tiles[1].iter().map(|tile| tile.to_char()).collect::<String>(), "BOHB");
+        let (tilesets, layers) = level.layers.unwrap();
+        assert_eq!(tilesets[0].image, "Tiles/tiles.png");
+        assert_eq!(layers[0].tiles[1], vec![1, 2, 2, 1]);
+        assert!(level.objects.is_empty());
+    }
+
+    // Hot reload keeps the running level when a file is missing or half written.
+    #[test]
+    fn broken_files_are_errors() {
+        let dir = assets("broken");
+        std::fs::remove_file(dir.join("level.map")).unwrap();
+        assert!(load_error(&dir).contains("can't read"));
+
+        let dir = assets("broken_layers");
+        std::fs::write(dir.join("level.layers.yaml"), "tilesets: [").unwrap();
+        assert!(load_error(&dir).contains("can't parse"));
+
+        std::fs::write(dir.join("level.layers.yaml"), "tilesets: []\nlayers:\n  - name: ground\n    kind: foreground\n    rows: [\"0 x\"]\n").unwrap();
+        assert!(load_error(&dir).contains("invalid tile id x"));
+
+        let dir = assets("broken_objects");
+        std::fs::write(dir.join("level.objects.yaml"), "objects: 3").unwrap();
+        assert!(load_error(&dir).contains("can't parse"));
+
+        let dir = assets("too_wide");
+        std::fs::write(dir.join("level.map"), format!("{}\n", "E".repeat(128))).unwrap();
+        assert!(load_error(&dir).contains("at most 127 tiles"));
+        std::fs::write(dir.join("level.map"), format!("{}\n", "E".repeat(127))).unwrap();
+        assert_eq!(load(&dir).unwrap().size().unwrap(), (127, 1));
+    }
+
+    #[test]
+    fn level_files_sit_next_to_the_map() {
+        assert_eq!(MapLoader::level_file("level.map", "layers.yaml"), "level.layers.yaml");
+        assert_eq!(MapLoader::level_file("Levels/cave.tmx", "background.yaml"), "Levels/cave.background.yaml");
+    }
+
+    #[test]
+    fn backgrounds_default_when_missing_and_report_broken_files() {
+        let dir = assets("background");
+        let map_loader = MapLoader::new(Rc::new(dir.clone()));
+        assert_eq!(map_loader.load_background("level.map").unwrap().len(), LayerConfig::defaults().len());
+
+        std::fs::write(dir.join("level.background.yaml"), "layers:
+  - image: [").unwrap();
+        let error = map_loader.load_background("level.map").err().unwrap();
+        assert!(error.contains("can't parse") && error.contains("level.background.yaml"));
+    }
+}
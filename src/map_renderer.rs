@@ -0,0 +1,165 @@
+use opengl_graphics::GlGraphics;
+use graphics::{Context, Graphics, ImageSize, BACK_END_MAX_VERTEX_COUNT};
+use graphics::math::Matrix2d;
+use graphics::triangulation::{tx, ty};
+use std::ops::Range;
+
+use super::map::{Map, LayerKind, Tileset, TileLayer};
+use super::texture_loader::{TextureLoader, TextureHandle};
+use super::colors;
+
+// Vertices handed to the back end per call, rounded down to whole quads.
+const CHUNK_SIZE: usize = BACK_END_MAX_VERTEX_COUNT / 6 * 6;
+
+// The visible tiles of one layer using one tileset, as two triangles per tile.
+pub struct TileBatch {
+    pub vertices: Vec<[f32; 2]>,
+    pub uvs: Vec<[f32; 2]>
+}
+
+pub struct TileBatcher {
+    batches: Vec<TileBatch>
+}
+
+impl TileBatcher {
+    pub fn new(tileset_count: usize) -> TileBatcher {
+        TileBatcher {
+            batches: (0..tileset_count)
+                .map(|_| TileBatch { vertices: Vec::new(), uvs: Vec::new() })
+                .collect()
+        }
+    }
+
+    pub fn batches(&self) -> &[TileBatch] {
+        &self.batches
+    }
+
+    // `view` is the visible world rect, tiles outside of it are skipped.
+    pub fn build(&mut self, map: &Map, layer: &TileLayer, view: [f64; 4], texture_sizes: &[(u32, u32)], transform: Matrix2d) {
+        for batch in self.batches.iter_mut() {
+            batch.vertices.clear();
+            batch.uvs.clear();
+        }
+
+        let (rows, columns) = visible_tiles(map, view);
+
+        for row in rows {
+            let gids = match layer.tiles.get(row) {
+                Some(gids) => gids,
+                None => break
+            };
+
+            for (column, &gid) in gids.iter().enumerate().take(columns.end).skip(columns.start) {
+                if gid == 0 {
+                    continue;
+                }
+
+                let tileset_index = match map.tileset_index(gid) {
+                    Some(index) => index,
+                    None => continue
+                };
+
+                let tileset = &map.tilesets[tileset_index];
+                let texture_size = texture_sizes[tileset_index];
+                let source = source_rect(tileset, texture_size, gid - tileset.first_gid);
+
+                let x = map.tile_size * column as f64 + map.position[0];
+                let y = map.tile_size * row as f64 + map.position[1];
+
+                push_quad(
+                    &mut self.batches[tileset_index],
+                    transform,
+                    [x, y, map.tile_size, map.tile_size],
+                    source,
+                    texture_size);
+            }
+        }
+    }
+}
+
+pub struct MapRenderer {
+    textures: Vec<TextureHandle>,
+    batcher: TileBatcher
+}
+
+impl MapRenderer {
+    pub fn new(map: &Map, texture_loader: &TextureLoader) -> MapRenderer {
+        MapRenderer {
+            textures: map.tilesets
+                .iter()
+                .map(|tileset| texture_loader.load_texture(&tileset.image))
+                .collect(),
+            batcher: TileBatcher::new(map.tilesets.len())
+        }
+    }
+
+    pub fn render(&mut self, ctx: &Context, gl: &mut GlGraphics, map: &Map, kind: LayerKind, view: [f64; 4]) {
+        let texture_sizes: Vec<(u32, u32)> = self.textures
+            .iter()
+            .map(|texture| texture.borrow().get_size())
+            .collect();
+
+        for layer in map.layers.iter().filter(|layer| layer.kind == kind) {
+            self.batcher.build(map, layer, view, &texture_sizes, ctx.transform);
+
+            for (batch, texture) in self.batcher.batches().iter().zip(self.textures.iter()) {
+                let texture = texture.borrow();
+                let texture = match texture.gl() {
+                    Some(texture) if !batch.vertices.is_empty() => texture,
+                    _ => continue
+                };
+
+                gl.tri_list_uv(&ctx.draw_state, &colors::WHITE, texture, |f| {
+                    for (vertices, uvs) in batch.vertices.chunks(CHUNK_SIZE).zip(batch.uvs.chunks(CHUNK_SIZE)) {
+                        f(vertices, uvs);
+                    }
+                });
+            }
+        }
+    }
+}
+
+fn visible_tiles(map: &Map, view: [f64; 4]) -> (Range<usize>, Range<usize>) {
+    let first = |start: f64| (start / map.tile_size).floor().max(0.0) as usize;
+    let last = |end: f64| (end / map.tile_size).ceil().max(0.0) as usize;
+
+    (first(view[1])..last(view[1] + view[3]), first(view[0])..last(view[0] + view[2]))
+}
+
+fn push_quad(batch: &mut TileBatch, transform: Matrix2d, rect: [f64; 4], source: [f64; 4], texture_size: (u32, u32)) {
+    let (x1, y1) = (rect[0], rect[1]);
+    let (x2, y2) = (rect[0] + rect[2], rect[1] + rect[3]);
+
+    let (width, height) = (texture_size.0 as f64, texture_size.1 as f64);
+    let (u1, v1) = ((source[0] / width) as f32, (source[1] / height) as f32);
+    let (u2, v2) = (((source[0] + source[2]) / width) as f32, ((source[1] + source[3]) / height) as f32);
+
+    let corner = |x: f64, y: f64| [tx(transform, x, y), ty(transform, x, y)];
+
+    batch.vertices.extend_from_slice(&[
+        corner(x1, y1), corner(x2, y1), corner(x1, y2),
+        corner(x2, y1), corner(x2, y2), corner(x1, y2)
+    ]);
+    batch.uvs.extend_from_slice(&[
+        [u1, v1], [u2, v1], [u1, v2],
+        [u2, v1], [u2, v2], [u1, v2]
+    ]);
+}
+
+fn source_rect(tileset: &Tileset, texture_size: (u32, u32), local_id: u32) -> [f64; 4] {
+    let (width, height) = (texture_size.0 as f64, texture_size.1 as f64);
+
+    let (tile_width, tile_height) = match (tileset.tile_width, tileset.tile_height) {
+        (Some(tile_width), Some(tile_height)) => (tile_width, tile_height),
+        _ => return [0.0, 0.0, width, height]
+    };
+
+    let columns = (((width - tileset.margin * 2.0 + tileset.spacing) / (tile_width + tileset.spacing)) as u32).max(1);
+    let column = (local_id % columns) as f64;
+    let row = (local_id / columns) as f64;
+
+    [tileset.margin + column * (tile_width + tileset.spacing),
+     tileset.margin + row * (tile_height + tileset.spacing),
+     tile_width,
+     tile_height]
+}
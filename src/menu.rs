@@ -0,0 +1,124 @@
+use opengl_graphics::{GlGraphics, GlyphCache};
+use piston::input::Button;
+use piston::input::Button::Keyboard;
+use piston::input::keyboard::Key;
+use graphics::Context;
+
+use super::textwriter::TextWriter;
+use super::colors;
+use super::config;
+
+pub struct MenuItem {
+    pub label: String,
+    pub enabled: bool
+}
+
+pub enum MenuInput {
+    Previous,
+    Next,
+    Select,
+    Back,
+    None
+}
+
+pub struct Menu {
+    items: Vec<MenuItem>,
+    selected: usize,
+    text_writer: TextWriter
+}
+
+impl Menu {
+    pub fn new(labels: &[&str]) -> Menu {
+        let items = labels
+            .iter()
+            .map(|label| MenuItem { label: label.to_string(), enabled: true })
+            .collect();
+
+        Menu {
+            items,
+            selected: 0,
+            text_writer: TextWriter::new()
+        }
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn set_label(&mut self, index: usize, label: &str) {
+        self.items[index].label = label.to_string();
+    }
+
+    pub fn set_enabled(&mut self, index: usize, enabled: bool) {
+        self.items[index].enabled = enabled;
+        if !self.items[self.selected].enabled {
+            self.select_next();
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        for step in 1..=self.items.len() {
+            let index = (self.selected + step) % self.items.len();
+            if self.items[index].enabled {
+                self.selected = index;
+                return;
+            }
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        for step in 1..=self.items.len() {
+            let index = (self.selected + self.items.len() * 2 - step) % self.items.len();
+            if self.items[index].enabled {
+                self.selected = index;
+                return;
+            }
+        }
+    }
+
+    pub fn handle_key(&mut self, args: &Button) -> MenuInput {
+        match *args {
+            Keyboard(Key::W) | Keyboard(Key::Up) => {
+                self.select_previous();
+                MenuInput::Previous
+            }
+            Keyboard(Key::S) | Keyboard(Key::Down) => {
+                self.select_next();
+                MenuInput::Next
+            }
+            Keyboard(Key::Return) | Keyboard(Key::Space) => MenuInput::Select,
+            Keyboard(Key::Escape) => MenuInput::Back,
+            _ => MenuInput::None
+        }
+    }
+
+    pub fn render(&mut self, ctx: &Context, gl: &mut GlGraphics, glyphs: &mut GlyphCache, pos_x: f64, pos_y: f64) {
+        let line_height = config::MENU_FONT_SIZE as f64 * 1.5;
+
+        for (i, item) in self.items.iter().enumerate() {
+            let color = if !item.enabled {
+                colors::DARK_GRAY
+            } else if i == self.selected {
+                colors::RED
+            } else {
+                colors::BLACK
+            };
+
+            let label = if i == self.selected {
+                format!("> {}", item.label)
+            } else {
+                format!("  {}", item.label)
+            };
+
+            self.text_writer.render_text(
+                ctx,
+                gl,
+                glyphs,
+                color,
+                config::MENU_FONT_SIZE,
+                pos_x,
+                pos_y + line_height * i as f64,
+                &label);
+        }
+    }
+}
@@ -6,7 +6,20 @@ use interpolation::Lerp;
 
 use super::AABB::AABB;
 use super::config;
-use super::map::{Map, AreaIndex};
+use super::map::{Map, AreaIndex, TileType};
+
+// The numbers that decide how far an object can walk, jump and fall.
+#[derive(Copy, Clone)]
+pub struct Movement {
+    pub size: Vec2d,
+    pub accelerate: f64,
+    pub max_speed: f64,
+    pub jump_speed: f64,
+    pub friction: f64,
+    pub gravity: f64,
+    pub fall_gravity: f64,
+    pub air_control: f64
+}
 
 pub struct MovingObject {
     pub object_id: String,
@@ -14,6 +27,10 @@ pub struct MovingObject {
     pub position: Vec2d,
     pub old_speed: Vec2d,
     pub speed: Vec2d,
+    // Speed from knockback, springs and the like, it isn't limited by walking and dies down on its own.
+    pub external_speed: Vec2d,
+    // Speed of whatever the object stands on, applied for a single update.
+    pub carry_speed: Vec2d,
     pub old_accelaration: Vec2d,
     pub acceleration: Vec2d,
     pub scale: Vec2d,
@@ -34,6 +51,9 @@ pub struct MovingObject {
     pub at_ceiling: bool,
 
     pub on_one_way_platform: bool,
+    // Tile the object stands on, `Empty` while in the air.
+    pub ground_tile: TileType,
+    pub climbing: bool,
     pub areas: Vec<AreaIndex>,
     pub all_colliding_objects: HashMap<String, CollisionData>,
 
@@ -53,6 +73,8 @@ impl MovingObject {
             acceleration: [0.0, config::GRAVITY],
             old_accelaration: [0.0, 0.0],
             speed: [0.0, 0.0],
+            external_speed: [0.0, 0.0],
+            carry_speed: [0.0, 0.0],
             old_speed: [0.0, 0.0],
             scale: [1.0, 1.0],
             pushed_right_wall: false,
@@ -64,6 +86,8 @@ impl MovingObject {
             was_at_ceiling: false,
             at_ceiling: false,
             on_one_way_platform: false,
+            ground_tile: TileType::Empty,
+            climbing: false,
             aabb: AABB::new(position, mul_scalar(size, 0.5)),
             aabb_offset: mul_scalar(size, 0.5),
             bounds: bounds,
@@ -79,9 +103,10 @@ impl MovingObject {
     fn handle_right_side_collision(&mut self, map: &Map) {
         let (collides, wall_x) = self.collides_right_side(&map);
         
-        if collides && self.speed[0] > 0.0 {
+        if collides && self.velocity()[0] > 0.0 {
             self.position[0] = wall_x - self.aabb.half_size[0] * 2.0;
             self.speed[0] = 0.0;
+            self.external_speed[0] = 0.0;
             self.pushes_right_wall = true;
             return;
         }
@@ -92,9 +117,10 @@ impl MovingObject {
     fn handle_left_side_collision(&mut self, map: &Map) {
         let (collides, wall_x) = self.collides_left_side(&map);
         
-        if collides && self.speed[0] < 0.0 {
+        if collides && self.velocity()[0] < 0.0 {
             self.position[0] = wall_x;
             self.speed[0] = 0.0;
+            self.external_speed[0] = 0.0;
             self.pushes_left_wall = true;
             return;
         } 
@@ -107,26 +133,38 @@ impl MovingObject {
         if self.position[1] >= height {
             self.position[1] = height;
             self.speed[1] = 0.0;
+            self.external_speed[1] = 0.0;
             self.on_ground = true;
+            self.ground_tile = TileType::Block;
             return
         }
-        let (has_ground, calculated_ground) = self.has_ground(&map);
+        // Climbing passes through the floors a ladder leads through.
+        if self.climbing && self.feet_on_ladder(map) {
+            self.on_ground = false;
+            self.ground_tile = TileType::Empty;
+            return
+        }
+        let (has_ground, calculated_ground, ground_tile) = self.has_ground(&map);
 
-        if self.speed[1] > 0.0 && has_ground {
+        if self.velocity()[1] > 0.0 && has_ground {
             self.position[1] = calculated_ground - self.aabb.half_size[1] * 2.0;
             self.speed[1] = 0.0;
+            self.external_speed[1] = 0.0;
             self.on_ground = true;
+            self.ground_tile = ground_tile;
         } else {
             self.on_ground = false;
+            self.ground_tile = TileType::Empty;
         }
     }
 
     fn check_ceiling_collision(&mut self, map: &Map) {
         let (has_ceiling, calculated_ceiling) = self.has_ceiling(&map);
 
-        if self.speed[1] < 0.0 && has_ceiling {
+        if self.velocity()[1] < 0.0 && has_ceiling {
             self.position[1] = calculated_ceiling;//  - self.aabb_offset[1];
             self.speed[1] = 0.0;
+            self.external_speed[1] = 0.0;
         }
     }
 
@@ -140,6 +178,42 @@ impl MovingObject {
         }
     }
 
+    pub fn movement(&self) -> Movement {
+        Movement {
+            size: mul_scalar(self.aabb.half_size, 2.0),
+            accelerate: self.accelerate,
+            max_speed: self.max_speed,
+            jump_speed: self.jump_speed,
+            friction: config::FRICTION,
+            gravity: config::GRAVITY,
+            fall_gravity: config::GRAVITY * config::FALL_GRAVITY_FACTOR,
+            air_control: config::AIR_CONTROL
+        }
+    }
+
+    // Everything that moves the object this update, its own speed included.
+    pub fn velocity(&self) -> Vec2d {
+        add(add(self.speed, self.external_speed), self.carry_speed)
+    }
+
+    pub fn apply_impulse(&mut self, impulse: Vec2d) {
+        self.external_speed = add(self.external_speed, impulse);
+    }
+
+    pub fn carry(&mut self, speed: Vec2d) {
+        self.carry_speed = speed;
+    }
+
+    fn damp_external_speed(&mut self, delta: f64) {
+        let damping = (-config::EXTERNAL_SPEED_DAMPING * delta).exp();
+        for speed in self.external_speed.iter_mut() {
+            *speed *= damping;
+            if speed.abs() < config::MIN_EXTERNAL_SPEED {
+                *speed = 0.0;
+            }
+        }
+    }
+
     pub fn stop(&mut self) {
         self.speed = [0.0, 0.0];
     }
@@ -164,17 +238,69 @@ impl MovingObject {
     }
 
     pub fn falling(&mut self) {
-        if self.speed[1] >= 0.0 {
-            self.acceleration[1] = config::GRAVITY * 7.5;
+        if self.velocity()[1] >= 0.0 {
+            self.acceleration[1] = config::GRAVITY * config::FALL_GRAVITY_FACTOR;
         }
     }
 
     pub fn stop_falling(&mut self) {
-        if self.speed[1] >= 0.0 {
+        if self.velocity()[1] >= 0.0 {
             self.acceleration[1] = config::GRAVITY;
         }
     }
 
+    // Moves straight up or down without gravity until `stop_climbing`.
+    pub fn climb(&mut self, speed: f64) {
+        self.speed = [0.0, speed];
+        self.external_speed = [0.0, 0.0];
+        self.acceleration = [0.0, 0.0];
+        self.climbing = true;
+    }
+
+    pub fn stop_climbing(&mut self) {
+        self.acceleration[1] = config::GRAVITY;
+        self.climbing = false;
+    }
+
+    // Centers the object on the ladder above (`offset` -1.0) or below (1.0) its feet, so it fits
+    // through the gap the ladder leads through, and climbs it.
+    pub fn grab_ladder(&mut self, map: &Map, offset: f64) {
+        if offset > 0.0 {
+            self.drop();
+        }
+        let (column, row) = self.tile_at_feet(map, offset);
+        let ladder_x = map.get_map_tile_position(column, row)[0] + map.tile_size / 2.0;
+        self.position[0] = ladder_x - self.aabb.half_size[0];
+        self.climb(offset.signum() * config::CLIMB_SPEED);
+    }
+
+    // Puts the feet on top of the ladder below them after climbing over its top.
+    pub fn stand_on_ladder(&mut self, map: &Map) {
+        let (column, row) = self.tile_at_feet(map, 1.0);
+        let top = map.get_map_tile_position(column, row)[1];
+        self.position[1] = top - self.aabb.half_size[1] * 2.0;
+        self.stop();
+    }
+
+    // Tile just above (`offset` -1.0) or just below (1.0) the middle of the object's feet.
+    pub fn tile_at_feet(&self, map: &Map, offset: f64) -> (i8, i8) {
+        let center = add(self.position, self.aabb_offset);
+        let feet = center[1] + self.aabb.half_size[1];
+        let tile = map.get_map_tile_in_point([center[0], feet + offset]);
+        (tile.x, tile.y)
+    }
+
+    pub fn ladder_at_feet(&self, map: &Map, offset: f64) -> bool {
+        let (column, row) = self.tile_at_feet(map, offset);
+        map.is_ladder(column, row)
+    }
+
+    fn feet_on_ladder(&self, map: &Map) -> bool {
+        let feet = [self.position[0] + self.aabb.half_size[0], self.position[1] + self.aabb.half_size[1] * 2.0];
+        let tile = map.get_map_tile_in_point(feet);
+        map.is_ladder(tile.x, tile.y)
+    }
+
     pub fn move_object(&mut self, x: f64, y: f64){
         self.position[0] += x * config::MAP_TILES_PARRALAX_FACTOR;
         self.position[1] += y * config::MAP_TILES_PARRALAX_FACTOR;
@@ -194,15 +320,33 @@ impl MovingObject {
         self.was_at_ceiling = self.at_ceiling;
 
         self.aabb.center = add(self.position, self.aabb_offset);
-        self.position = add(self.position, mul_scalar(self.speed, delta));
+        self.position = add(self.position, mul_scalar(self.velocity(), delta));
 
         self.handle_left_side_collision(&map);
         self.handle_right_side_collision(&map);
         self.check_ground_collision(&map);
         self.check_ceiling_collision(&map);
+
+        self.damp_external_speed(delta);
+        self.carry_speed = [0.0, 0.0];
+        self.apply_ground_tile();
+    }
+
+    // Springs and conveyors act on the next update of whatever stands on them.
+    fn apply_ground_tile(&mut self) {
+        if !self.on_ground {
+            return;
+        }
+
+        match self.ground_tile {
+            TileType::Spring => self.apply_impulse([0.0, -config::SPRING_IMPULSE]),
+            TileType::ConveyorLeft => self.carry([-config::CONVEYOR_SPEED, 0.0]),
+            TileType::ConveyorRight => self.carry([config::CONVEYOR_SPEED, 0.0]),
+            _ => {}
+        }
     }
 
-    pub fn has_ground(&mut self, map: &Map) -> (bool, f64) {
+    pub fn has_ground(&mut self, map: &Map) -> (bool, f64, TileType) {
         let (new_bottom_right, new_bottom_left, _, _) = self.get_sensors(self.position);
         let (old_bottom_right, old_bottom_left, _, _) = self.get_sensors(self.old_position);
 
@@ -226,17 +370,17 @@ impl MovingObject {
                 let ground_y = tileIndexY as f64 * map.tile_size + map.position[1];
                 if map.is_obstacle(tile_index_x, tileIndexY){
                     self.on_one_way_platform = false;
-                    return (true, ground_y);
+                    return (true, ground_y, map.get_tile(tile_index_x, tileIndexY));
                 }
                 if map.is_one_way_platform(tile_index_x, tileIndexY)
                     && (checked_tile[1] - ground_y).abs() < self.one_way_platform_tsh
                 {
                     self.on_one_way_platform = true;
-                    return (true, ground_y);
+                    return (true, ground_y, TileType::OneWay);
                 }
             }
         }
-        (false, 0.0)
+        (false, 0.0, TileType::Empty)
     }
 
     pub fn has_ceiling(&mut self, map: &Map) -> (bool, f64) {
@@ -340,7 +484,7 @@ impl MovingObject {
         (false, 0.0)
     }
 
-    fn get_sensors(&self, position: Vec2d) -> (Vec2d, Vec2d, Vec2d, Vec2d) {
+    pub fn get_sensors(&self, position: Vec2d) -> (Vec2d, Vec2d, Vec2d, Vec2d) {
         let center = add(position, self.aabb.half_size);
         let bottom_right = add(add(center, self.aabb.half_size), [1.0, 1.0]);
         let top_right = [center[0] + self.aabb.half_size[0] + 1.0, center[1] - self.aabb.half_size[1] - 1.0];
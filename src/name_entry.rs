@@ -0,0 +1,116 @@
+use opengl_graphics::{GlGraphics, GlyphCache};
+use piston::input::{UpdateArgs, Button};
+use piston::input::Button::Keyboard;
+use piston::input::keyboard::Key;
+use graphics::Context;
+
+use super::gamestate::GameState;
+use super::gamedata::GameData;
+use super::save_data::HighScore;
+use super::states::State;
+use super::textwriter::TextWriter;
+use super::colors;
+use super::config;
+
+pub struct NameEntry {
+    name: String,
+    confirmed: bool,
+    text_writer: TextWriter,
+    game_data: Option<GameData>
+}
+
+impl NameEntry {
+    pub fn new(game_data: GameData) -> NameEntry {
+        let name = if game_data.username == "unknown" {
+            String::new()
+        } else {
+            game_data.username.clone()
+        };
+
+        NameEntry {
+            name,
+            confirmed: false,
+            text_writer: TextWriter::new(),
+            game_data: Some(game_data)
+        }
+    }
+
+    fn submit(&mut self) -> GameData {
+        let mut data = self.game_data.take().unwrap();
+        data.username = self.name.trim().to_string();
+
+        if let Some(result) = data.last_result.as_ref() {
+            let entry = HighScore {
+                name: data.username.clone(),
+                score: result.score,
+                time: result.time
+            };
+            let level = result.level.clone();
+            data.progress.add_high_score(&level, entry);
+        }
+
+        data.save();
+        data
+    }
+}
+
+impl GameState for NameEntry {
+    fn render(&mut self, ctx: &Context, gl: &mut GlGraphics, glyphs: &mut GlyphCache) {
+        let left = config::BOARD_SIZE_X as f64 / 3.0;
+        let top = config::BOARD_SIZE_Y as f64 / 3.0;
+        let line_height = config::MENU_FONT_SIZE as f64 * 1.5;
+
+        let (score, time) = match self.game_data.as_ref().and_then(|data| data.last_result.as_ref()) {
+            Some(result) => (result.score, result.time),
+            None => (0, 0.0)
+        };
+
+        let lines = [
+            "Level complete!".to_string(),
+            format!("Score: {}", score),
+            format!("Time: {:.1}s", time),
+            format!("Name: {}_", self.name)
+        ];
+
+        for (i, line) in lines.iter().enumerate() {
+            self.text_writer.render_text(
+                ctx,
+                gl,
+                glyphs,
+                colors::BLACK,
+                config::MENU_FONT_SIZE,
+                left,
+                top + line_height * i as f64,
+                line);
+        }
+    }
+
+    fn update(&mut self, _args: &UpdateArgs) -> State<GameData> {
+        if self.confirmed {
+            return State::Leaderboard(self.submit());
+        }
+        State::None
+    }
+
+    fn key_press(&mut self, args: &Button) {
+        match *args {
+            Keyboard(Key::Backspace) => {
+                self.name.pop();
+            }
+            Keyboard(Key::Return) => {
+                self.confirmed = !self.name.trim().is_empty();
+            }
+            _ => {}
+        }
+    }
+
+    fn key_release(&mut self, _args: &Button) {}
+
+    fn text_input(&mut self, text: &str) {
+        for c in text.chars() {
+            if (c.is_alphanumeric() || c == ' ') && self.name.chars().count() < config::MAX_USERNAME_LENGTH {
+                self.name.push(c);
+            }
+        }
+    }
+}
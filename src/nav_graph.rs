@@ -0,0 +1,691 @@
+use graphics::math::Vec2d;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+use super::config;
+use super::map::{Map, TileType};
+use super::moving_object::Movement;
+
+// Longest jump or fall that is followed before giving up on it.
+const MAX_AIR_TIME: f64 = 10.0;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum EdgeKind {
+    Walk,
+    Jump,
+    Fall,
+    Drop,
+    Climb
+}
+
+impl EdgeKind {
+    // Rough cost of taking an edge, walking is preferred over leaving the ground.
+    fn cost(self) -> u32 {
+        match self {
+            EdgeKind::Walk => 1,
+            EdgeKind::Fall | EdgeKind::Drop | EdgeKind::Climb => 2,
+            EdgeKind::Jump => 3
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Edge {
+    pub from: usize,
+    pub to: usize,
+    pub kind: EdgeKind,
+    // Where the character leaves the ground, its horizontal speed then and the input held in
+    // the air, -1.0 is left.
+    pub start: Vec2d,
+    pub speed: f64,
+    pub direction: f64
+}
+
+// A place the character can stand, its left side on `column` and its feet on top of `row`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Node {
+    pub column: i32,
+    pub row: i32
+}
+
+pub struct NavGraph {
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Vec<Edge>>,
+    index: HashMap<Node, usize>,
+    movement: Movement,
+    tile_size: f64
+}
+
+impl NavGraph {
+    pub fn new(map: &Map, movement: Movement) -> NavGraph {
+        let mut graph = NavGraph {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            index: HashMap::new(),
+            movement,
+            tile_size: map.tile_size
+        };
+
+        let (width, height) = (map.tiles.first().map_or(0, |row| row.len()) as i32, map.tiles.len() as i32);
+        for row in 0..=height {
+            for column in 0..width {
+                let node = Node { column, row };
+                if graph.can_stand(map, node) {
+                    graph.index.insert(node, graph.nodes.len());
+                    graph.nodes.push(node);
+                }
+            }
+        }
+
+        graph.edges = (0..graph.nodes.len()).map(|node| graph.find_edges(map, node)).collect();
+        graph
+    }
+
+    pub fn movement(&self) -> Movement {
+        self.movement
+    }
+
+    pub fn node_at(&self, node: Node) -> Option<usize> {
+        self.index.get(&node).copied()
+    }
+
+    // Top left corner of the character standing on a node.
+    pub fn position(&self, node: usize) -> Vec2d {
+        let node = self.nodes[node];
+        [node.column as f64 * self.tile_size, node.row as f64 * self.tile_size - self.movement.size[1]]
+    }
+
+    // The node a character standing at `position` is on.
+    pub fn node_under(&self, position: Vec2d) -> Option<usize> {
+        let column = position[0] / self.tile_size;
+        let row = ((position[1] + self.movement.size[1]) / self.tile_size).round() as i32;
+
+        [column.round(), column.floor(), column.ceil()]
+            .iter()
+            .find_map(|column| self.node_at(Node { column: *column as i32, row }))
+    }
+
+    // Where a character at `position` ends up after falling without any input.
+    pub fn landing(&self, map: &Map, position: Vec2d) -> Option<usize> {
+        self.simulate(map, position, [0.0, 0.0], 0.0, None, &mut |_| {})
+    }
+
+    pub fn reachable_from(&self, start: usize) -> Vec<bool> {
+        let mut reachable = vec![false; self.nodes.len()];
+        let mut queue = VecDeque::new();
+        reachable[start] = true;
+        queue.push_back(start);
+
+        while let Some(node) = queue.pop_front() {
+            for edge in self.edges[node].iter() {
+                if !reachable[edge.to] {
+                    reachable[edge.to] = true;
+                    queue.push_back(edge.to);
+                }
+            }
+        }
+
+        reachable
+    }
+
+    // Cheapest edges leading from one node to another.
+    pub fn find_path(&self, from: usize, to: usize) -> Option<Vec<Edge>> {
+        let mut costs = vec![u32::MAX; self.nodes.len()];
+        let mut previous: Vec<Option<(usize, Edge)>> = vec![None; self.nodes.len()];
+        let mut queue = BinaryHeap::new();
+        costs[from] = 0;
+        queue.push(Reverse((0, from)));
+
+        while let Some(Reverse((cost, node))) = queue.pop() {
+            if node == to {
+                break;
+            }
+            if cost > costs[node] {
+                continue;
+            }
+
+            for edge in self.edges[node].iter() {
+                let next_cost = cost + edge.kind.cost();
+                if next_cost < costs[edge.to] {
+                    costs[edge.to] = next_cost;
+                    previous[edge.to] = Some((node, *edge));
+                    queue.push(Reverse((next_cost, edge.to)));
+                }
+            }
+        }
+
+        if costs[to] == u32::MAX {
+            return None;
+        }
+
+        let mut path = Vec::new();
+        let mut node = to;
+        while let Some((from, edge)) = previous[node] {
+            path.push(edge);
+            node = from;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    // Calls `visit` with every area the character covers standing on a node or leaving it.
+    pub fn sweep(&self, map: &Map, node: usize, visit: &mut dyn FnMut([f64; 4])) {
+        let position = self.position(node);
+        visit([position[0], position[1], self.movement.size[0], self.movement.size[1]]);
+
+        for (start, speed, direction, ignored_row) in self.departures(map, node) {
+            self.simulate(map, start, speed, direction, ignored_row, visit);
+        }
+        for offset in [-1, 1].iter() {
+            self.climb(map, node, *offset, visit);
+        }
+    }
+
+    fn can_stand(&self, map: &Map, node: Node) -> bool {
+        let position = [node.column as f64 * self.tile_size, node.row as f64 * self.tile_size - self.movement.size[1]];
+        if position[0] + self.movement.size[0] > map.size()[0] || self.overlaps_block(map, position) {
+            return false;
+        }
+
+        // Springs throw the character back up, it never stays on them.
+        match self.ground_tile(map, position[0], node.row, None) {
+            Some(TileType::Spring) | None => false,
+            Some(_) => true
+        }
+    }
+
+    fn find_edges(&self, map: &Map, from: usize) -> Vec<Edge> {
+        let node = self.nodes[from];
+        let mut edges = Vec::new();
+
+        for side in [-1, 1].iter() {
+            if let Some(to) = self.node_at(Node { column: node.column + side, row: node.row }) {
+                edges.push(Edge { from, to, kind: EdgeKind::Walk, start: self.position(from), speed: 0.0, direction: *side as f64 });
+            }
+        }
+
+        for (start, speed, direction, ignored_row) in self.departures(map, from) {
+            let kind = if ignored_row.is_some() {
+                EdgeKind::Drop
+            } else if speed[1] < 0.0 {
+                EdgeKind::Jump
+            } else {
+                EdgeKind::Fall
+            };
+
+            if let Some(to) = self.simulate(map, start, speed, direction, ignored_row, &mut |_| {}) {
+                if to != from && !edges.iter().any(|edge| edge.to == to) {
+                    edges.push(Edge { from, to, kind, start, speed: speed[0], direction });
+                }
+            }
+        }
+
+        // A climb is kept even when a jump reaches the same node, it costs less.
+        for offset in [-1, 1].iter() {
+            if let Some(to) = self.climb(map, from, *offset, &mut |_| {}) {
+                if to != from && !edges.iter().any(|edge| edge.to == to && edge.kind == EdgeKind::Climb) {
+                    edges.push(Edge { from, to, kind: EdgeKind::Climb, start: self.position(from), speed: 0.0, direction: 0.0 });
+                }
+            }
+        }
+
+        edges
+    }
+
+    // Follows the ladder above (`offset` -1) or below (1) the middle of the character's feet the
+    // way `Character` climbs it: up until standing on its top, down until the ground or its bottom
+    // end, falling from there.
+    fn climb(&self, map: &Map, from: usize, offset: i32, visit: &mut dyn FnMut([f64; 4])) -> Option<usize> {
+        let node = self.nodes[from];
+        let size = self.movement.size;
+        let column = ((node.column as f64 * self.tile_size + size[0] / 2.0) / self.tile_size).floor() as i32;
+        let first_row = if offset < 0 { node.row - 1 } else { node.row };
+        if !map.is_ladder(column as i8, first_row as i8) {
+            return None;
+        }
+
+        let mut last_row = first_row;
+        while map.is_ladder(column as i8, (last_row + offset) as i8) {
+            last_row += offset;
+        }
+
+        // Centered on the ladder like `MovingObject::grab_ladder`.
+        let x = column as f64 * self.tile_size + (self.tile_size - size[0]) / 2.0;
+        let end_row = if offset < 0 { last_row } else { last_row + 1 };
+        let mut row = node.row;
+        while row != end_row {
+            row += offset;
+            let position = [x, row as f64 * self.tile_size - size[1]];
+            if self.overlaps_block(map, position) {
+                return None;
+            }
+            visit([position[0], position[1], size[0], size[1]]);
+        }
+
+        let end = [x, end_row as f64 * self.tile_size - size[1]];
+        if offset < 0 {
+            self.node_under(end)
+        } else {
+            self.simulate(map, end, [0.0, 0.0], 0.0, None, visit)
+        }
+    }
+
+    // Ways of leaving a node: jumping, walking off an edge and dropping through one way platforms,
+    // each with a few starting speeds and held directions.
+    fn departures(&self, map: &Map, from: usize) -> Vec<(Vec2d, Vec2d, f64, Option<i32>)> {
+        let node = self.nodes[from];
+        let position = self.position(from);
+        let max_speed = self.movement.max_speed;
+        let mut departures = Vec::new();
+
+        for speed in [-1.0, -0.5, 0.0, 0.5, 1.0].iter() {
+            for direction in [-1.0, 0.0, 1.0].iter() {
+                departures.push((position, [speed * max_speed, -self.movement.jump_speed], *direction, None));
+            }
+        }
+
+        for side in [-1, 1].iter() {
+            let edge = Node { column: node.column + side, row: node.row };
+            let start = [edge.column as f64 * self.tile_size, position[1]];
+            if self.node_at(edge).is_some() || self.overlaps_block(map, start) {
+                continue;
+            }
+
+            for speed in [0.0, 0.5, 1.0].iter() {
+                for direction in [-1.0, 0.0, 1.0].iter() {
+                    departures.push((start, [speed * max_speed * *side as f64, 0.0], *direction, None));
+                }
+            }
+        }
+
+        let (first, last) = self.columns(position[0]);
+        if (first..=last).all(|column| !map.is_obstacle(column as i8, node.row as i8)) {
+            for direction in [-1.0, 0.0, 1.0].iter() {
+                departures.push((position, [0.0, 0.0], *direction, Some(node.row)));
+            }
+        }
+
+        departures
+    }
+
+    // Steps the character through the air the way `MovingObject::update_physics` does until it lands,
+    // bouncing off springs on the way.
+    fn simulate(&self, map: &Map, start: Vec2d, speed: Vec2d, direction: f64, ignored_row: Option<i32>, visit: &mut dyn FnMut([f64; 4])) -> Option<usize> {
+        let delta = 1.0 / config::UPS as f64;
+        let movement = &self.movement;
+        let mut position = start;
+        let mut speed = speed;
+        let mut external_y = 0.0;
+        let mut acceleration_x = -speed[0] * movement.friction;
+
+        // A conveyor carries the character for the update it leaves the ground in.
+        let start_row = ((start[1] + movement.size[1]) / self.tile_size).round() as i32;
+        let on_ground = (start_row as f64 * self.tile_size - start[1] - movement.size[1]).abs() < 1e-6;
+        let mut carry = match self.ground_tile(map, start[0], start_row, ignored_row) {
+            Some(TileType::ConveyorLeft) if on_ground => -config::CONVEYOR_SPEED,
+            Some(TileType::ConveyorRight) if on_ground => config::CONVEYOR_SPEED,
+            _ => 0.0
+        };
+
+        for _ in 0..(MAX_AIR_TIME / delta) as usize {
+            if direction != 0.0 {
+                acceleration_x = direction * movement.accelerate * movement.air_control;
+            }
+            speed[0] = (speed[0] + acceleration_x * delta).max(-movement.max_speed).min(movement.max_speed);
+            acceleration_x = -speed[0] * movement.friction;
+
+            let gravity = if speed[1] + external_y >= 0.0 { movement.fall_gravity } else { movement.gravity };
+            speed[1] += gravity * delta;
+
+            let moved_x = [position[0] + (speed[0] + carry) * delta, position[1]];
+            carry = 0.0;
+            if self.overlaps_block(map, moved_x) {
+                speed[0] = 0.0;
+            } else {
+                position = moved_x;
+            }
+
+            let velocity_y = speed[1] + external_y;
+            let bottom = position[1] + movement.size[1];
+            let moved_y = [position[0], position[1] + velocity_y * delta];
+            let mut landed = false;
+            if velocity_y > 0.0 {
+                // Falling fast crosses more than one row per step, so every row passed is checked.
+                let (first, last) = self.columns(position[0]);
+                let first_row = (bottom / self.tile_size).ceil() as i32;
+                let last_row = ((moved_y[1] + movement.size[1]) / self.tile_size).floor() as i32;
+
+                for row in first_row..=last_row {
+                    match self.ground_tile(map, position[0], row, ignored_row) {
+                        Some(TileType::Spring) => {
+                            position[1] = row as f64 * self.tile_size - movement.size[1];
+                            speed[1] = 0.0;
+                            external_y = -config::SPRING_IMPULSE;
+                            landed = true;
+                            break;
+                        },
+                        Some(_) => {
+                            visit([position[0], row as f64 * self.tile_size - movement.size[1], movement.size[0], movement.size[1]]);
+                            return (first..=last)
+                                .map(|column| Node { column, row })
+                                .find_map(|node| self.node_at(node));
+                        },
+                        None => {}
+                    }
+                }
+            }
+
+            if landed {
+                visit([position[0], position[1], movement.size[0], movement.size[1]]);
+                continue;
+            }
+
+            if self.overlaps_block(map, moved_y) {
+                speed[1] = 0.0;
+                external_y = 0.0;
+            } else {
+                position = moved_y;
+            }
+
+            external_y *= (-config::EXTERNAL_SPEED_DAMPING * delta).exp();
+            if external_y.abs() < config::MIN_EXTERNAL_SPEED {
+                external_y = 0.0;
+            }
+
+            visit([position[0], position[1], movement.size[0], movement.size[1]]);
+        }
+
+        None
+    }
+
+    // The tile a character with its left side at `x` lands on in `row`, the leftmost one
+    // like `MovingObject::has_ground` picks.
+    fn ground_tile(&self, map: &Map, x: f64, row: i32, ignored_row: Option<i32>) -> Option<TileType> {
+        let (first, last) = self.columns(x);
+        (first..=last)
+            .find(|column| self.is_ground(map, *column, row, ignored_row))
+            .map(|column| if map.is_obstacle(column as i8, row as i8) {
+                map.get_tile(column as i8, row as i8)
+            } else {
+                TileType::OneWay
+            })
+    }
+
+    fn is_ground(&self, map: &Map, column: i32, row: i32, ignored_row: Option<i32>) -> bool {
+        map.is_obstacle(column as i8, row as i8)
+            || (ignored_row != Some(row) && map.is_one_way_platform(column as i8, row as i8))
+    }
+
+    fn overlaps_block(&self, map: &Map, position: Vec2d) -> bool {
+        let (first_column, last_column) = self.columns(position[0]);
+        let first_row = (position[1] / self.tile_size).floor() as i32;
+        let last_row = ((position[1] + self.movement.size[1]) / self.tile_size).ceil() as i32 - 1;
+
+        (first_row..=last_row).any(|row| {
+            (first_column..=last_column).any(|column| map.is_obstacle(column as i8, row as i8))
+        })
+    }
+
+    // Tile columns covered by the character with its left side at `x`.
+    fn columns(&self, x: f64) -> (i32, i32) {
+        let first = (x / self.tile_size).floor() as i32;
+        let last = ((x + self.movement.size[0]) / self.tile_size).ceil() as i32 - 1;
+        (first, last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::moving_object::MovingObject;
+
+    const TILE_SIZE: f64 = 24.0;
+
+    fn map(rows: &[&str]) -> Map {
+        let tiles: Vec<Vec<TileType>> = rows
+            .iter()
+            .map(|row| row.chars().map(|c| TileType::from_char(c).unwrap()).collect())
+            .collect();
+        let (width, height) = (tiles[0].len() as i8, tiles.len() as i8);
+        Map::new(tiles, [0.0, 0.0], width, height, TILE_SIZE)
+    }
+
+    fn movement() -> Movement {
+        let character = MovingObject::new(
+            [0.0, 0.0],
+            config::CHARACTER_SIZE,
+            [0.0, 1080.0],
+            config::ACCELERATION,
+            config::WALK_SPEED,
+            config::JUMP_SPEED,
+            "character".to_string());
+        character.movement()
+    }
+
+    fn nav_graph(map: &Map) -> NavGraph {
+        NavGraph::new(map, movement())
+    }
+
+    // Nodes in a row with only the given edges between them.
+    fn hand_built_graph(count: usize, edges: &[(usize, usize, EdgeKind)]) -> NavGraph {
+        let nodes: Vec<Node> = (0..count).map(|column| Node { column: column as i32, row: 0 }).collect();
+        let mut graph = NavGraph {
+            index: nodes.iter().enumerate().map(|(i, node)| (*node, i)).collect(),
+            nodes,
+            edges: vec![Vec::new(); count],
+            movement: movement(),
+            tile_size: TILE_SIZE
+        };
+        for (from, to, kind) in edges.iter() {
+            graph.edges[*from].push(Edge { from: *from, to: *to, kind: *kind, start: [0.0, 0.0], speed: 0.0, direction: 0.0 });
+        }
+        graph
+    }
+
+    fn kinds(path: &[Edge]) -> Vec<EdgeKind> {
+        path.iter().map(|edge| edge.kind).collect()
+    }
+
+    fn node(graph: &NavGraph, column: i32, row: i32) -> usize {
+        graph.node_at(Node { column, row }).unwrap()
+    }
+
+    // A floor with a ledge low enough to jump on, a one way platform to drop through and a
+    // platform too high to reach from either.
+    fn edge_level() -> Map {
+        let mut rows = vec!["EEEEEEEEEEEEEEEE"; 16];
+        rows[3] = "EEEEEEEEEEEEBBBB";
+        rows[11] = "EEEOOOOEEEEEEEEE";
+        rows[12] = "EEEEEEEEEEBBBBEE";
+        rows[15] = "BBBBBBBBBBBBBBBB";
+        map(&rows)
+    }
+
+    fn connects(graph: &NavGraph, from_row: i32, to_row: i32, kind: EdgeKind) -> bool {
+        graph.nodes.iter().enumerate()
+            .filter(|(_, node)| node.row == from_row)
+            .any(|(from, _)| graph.edges[from].iter().any(|edge| edge.kind == kind && graph.nodes[edge.to].row == to_row))
+    }
+
+    #[test]
+    fn nodes_are_where_the_character_fits_on_ground() {
+        let map = edge_level();
+        let graph = nav_graph(&map);
+
+        assert!(graph.node_at(Node { column: 0, row: 15 }).is_some());
+        assert!(graph.node_at(Node { column: 8, row: 12 }).is_some());
+        assert!(graph.node_at(Node { column: 14, row: 12 }).is_none());
+        // Past the right edge of the map and under the ledge there's no room for the character.
+        assert!(graph.node_at(Node { column: 14, row: 15 }).is_none());
+        assert!(graph.node_at(Node { column: 11, row: 15 }).is_none());
+    }
+
+    #[test]
+    fn walk_edges_join_neighbours() {
+        let map = edge_level();
+        let graph = nav_graph(&map);
+        let (left, right) = (node(&graph, 0, 15), node(&graph, 1, 15));
+
+        assert!(has_edge(&graph, left, right, EdgeKind::Walk));
+        assert!(has_edge(&graph, right, left, EdgeKind::Walk));
+        assert!(!has_edge(&graph, left, node(&graph, 2, 15), EdgeKind::Walk));
+    }
+
+    #[test]
+    fn jump_fall_and_drop_edges() {
+        let map = edge_level();
+        let graph = nav_graph(&map);
+
+        assert!(connects(&graph, 15, 12, EdgeKind::Jump));
+        assert!(connects(&graph, 12, 15, EdgeKind::Fall));
+        assert!(connects(&graph, 11, 15, EdgeKind::Drop));
+        assert!(!connects(&graph, 15, 15, EdgeKind::Drop));
+        assert!(!connects(&graph, 12, 3, EdgeKind::Jump));
+
+        // Falls start a tile past the ledge, enemies walk from the node towards the start.
+        for (from, edges) in graph.edges.iter().enumerate() {
+            for edge in edges.iter().filter(|edge| edge.kind == EdgeKind::Fall) {
+                assert_eq!(edge.from, from);
+                assert_eq!((edge.start[0] - graph.position(from)[0]).abs(), TILE_SIZE);
+            }
+        }
+    }
+
+    #[test]
+    fn landing_finds_the_ground_below() {
+        let map = edge_level();
+        let graph = nav_graph(&map);
+
+        assert_eq!(graph.landing(&map, [0.0, 100.0]), Some(node(&graph, 0, 15)));
+        assert_eq!(graph.landing(&map, [72.0, 100.0]), Some(node(&graph, 3, 11)));
+    }
+
+    #[test]
+    fn reachable_from_follows_edges() {
+        let map = edge_level();
+        let graph = nav_graph(&map);
+        let reachable = graph.reachable_from(node(&graph, 0, 15));
+
+        assert!(reachable[node(&graph, 7, 15)]);
+        assert!(reachable[node(&graph, 10, 12)]);
+        assert!(reachable[node(&graph, 4, 11)]);
+        assert!(!reachable[node(&graph, 13, 3)]);
+        assert!(graph.reachable_from(node(&graph, 13, 3))[node(&graph, 0, 15)]);
+    }
+
+    #[test]
+    fn find_path_prefers_walking_over_a_jump() {
+        let graph = hand_built_graph(3, &[
+            (0, 2, EdgeKind::Jump),
+            (0, 1, EdgeKind::Walk),
+            (1, 2, EdgeKind::Walk)
+        ]);
+
+        assert_eq!(kinds(&graph.find_path(0, 2).unwrap()), vec![EdgeKind::Walk, EdgeKind::Walk]);
+    }
+
+    #[test]
+    fn find_path_jumps_when_walking_costs_more() {
+        let graph = hand_built_graph(6, &[
+            (0, 1, EdgeKind::Walk),
+            (1, 2, EdgeKind::Walk),
+            (2, 3, EdgeKind::Walk),
+            (3, 4, EdgeKind::Walk),
+            (4, 5, EdgeKind::Walk),
+            (0, 4, EdgeKind::Jump)
+        ]);
+
+        assert_eq!(kinds(&graph.find_path(0, 4).unwrap()), vec![EdgeKind::Jump]);
+        assert_eq!(kinds(&graph.find_path(0, 5).unwrap()), vec![EdgeKind::Jump, EdgeKind::Walk]);
+        assert_eq!(kinds(&graph.find_path(1, 5).unwrap()), vec![EdgeKind::Walk; 4]);
+    }
+
+    #[test]
+    fn find_path_without_a_way_there() {
+        let graph = hand_built_graph(3, &[(0, 1, EdgeKind::Walk), (2, 1, EdgeKind::Fall)]);
+
+        assert!(graph.find_path(0, 2).is_none());
+        assert!(graph.find_path(1, 0).is_none());
+        assert!(graph.find_path(0, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn find_path_on_a_level_ends_at_the_target() {
+        let map = edge_level();
+        let graph = nav_graph(&map);
+        let (from, to) = (node(&graph, 0, 15), node(&graph, 10, 12));
+
+        let path = graph.find_path(from, to).unwrap();
+
+        assert_eq!(path.last().unwrap().to, to);
+        assert!(path.iter().any(|edge| edge.kind == EdgeKind::Jump));
+        for (edge, next) in path.iter().zip(path.iter().skip(1)) {
+            assert!(graph.edges[edge.to].iter().any(|candidate| candidate.to == next.to && candidate.kind == next.kind));
+        }
+        assert_eq!(kinds(&graph.find_path(from, node(&graph, 2, 15)).unwrap()), vec![EdgeKind::Walk, EdgeKind::Walk]);
+    }
+
+    // A ledge ten rows up, too high to jump to, above a floor that starts with a spring.
+    fn spring_level(spring: char) -> Map {
+        let mut rows = vec!["EEEEEEEEEEEE".to_string(); 20];
+        rows[9] = "OOOOOOOOEEEE".to_string();
+        rows[19] = format!("{}BBBBBBBB", spring.to_string().repeat(4));
+        map(&rows.iter().map(String::as_str).collect::<Vec<_>>())
+    }
+
+    // A floor eight rows up, too high to jump to, with a ladder leading up through a gap in it.
+    fn ladder_level(ladder: char) -> Map {
+        let mut rows = vec!["EEEEEEEEEEEE".to_string(); 12];
+        rows[3] = format!("BBBBE{}EBBBBB", ladder);
+        for row in rows.iter_mut().take(11).skip(4) {
+            *row = format!("EEEEE{}EEEEEE", ladder);
+        }
+        rows[11] = "BBBBBBBBBBBB".to_string();
+        map(&rows.iter().map(String::as_str).collect::<Vec<_>>())
+    }
+
+    fn has_edge(graph: &NavGraph, from: usize, to: usize, kind: EdgeKind) -> bool {
+        graph.edges[from].iter().any(|edge| edge.to == to && edge.kind == kind)
+    }
+
+    #[test]
+    fn ladders_are_climbed_up_and_down() {
+        let map = ladder_level('H');
+        let graph = nav_graph(&map);
+        let (bottom, top) = (node(&graph, 4, 11), node(&graph, 4, 3));
+
+        assert!(has_edge(&graph, bottom, top, EdgeKind::Climb));
+        assert!(has_edge(&graph, top, bottom, EdgeKind::Climb));
+        assert!(graph.reachable_from(bottom)[node(&graph, 0, 3)]);
+        assert!(graph.edges[node(&graph, 0, 11)].iter().all(|edge| edge.kind != EdgeKind::Climb));
+    }
+
+    #[test]
+    fn ladders_are_the_only_way_up() {
+        let map = ladder_level('E');
+        let graph = nav_graph(&map);
+
+        assert!(!graph.reachable_from(node(&graph, 4, 11))[node(&graph, 0, 3)]);
+    }
+
+    #[test]
+    fn springs_are_not_standing_places() {
+        let map = spring_level('S');
+        let graph = nav_graph(&map);
+
+        assert!(graph.node_at(Node { column: 0, row: 19 }).is_none());
+        assert!(graph.node_at(Node { column: 3, row: 19 }).is_none());
+        assert!(graph.node_at(Node { column: 4, row: 19 }).is_some());
+    }
+
+    #[test]
+    fn springs_reach_ledges_jumps_dont() {
+        let map = spring_level('B');
+        let graph = nav_graph(&map);
+        assert!(!graph.reachable_from(node(&graph, 6, 19))[node(&graph, 2, 9)]);
+
+        let map = spring_level('S');
+        let graph = nav_graph(&map);
+        assert!(graph.reachable_from(node(&graph, 6, 19))[node(&graph, 2, 9)]);
+    }
+}
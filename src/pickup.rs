@@ -0,0 +1,52 @@
+use opengl_graphics::GlGraphics;
+use graphics::Context;
+use graphics::math::Vec2d;
+
+use super::map_loader::LevelObject;
+use super::colors;
+use super::config;
+
+pub struct Pickup {
+    pub position: Vec2d,
+    pub size: Vec2d,
+    pub score: i32,
+    pub collected: bool
+}
+
+impl Pickup {
+    pub fn new(object: &LevelObject) -> Pickup {
+        let size = if object.size[0] > 0.0 && object.size[1] > 0.0 {
+            object.size
+        } else {
+            [config::PICKUP_SIZE, config::PICKUP_SIZE]
+        };
+
+        Pickup {
+            position: object.position,
+            size,
+            score: object.properties
+                .get("score")
+                .and_then(|score| score.parse().ok())
+                .unwrap_or(config::PICKUP_SCORE),
+            collected: false
+        }
+    }
+
+    pub fn overlaps(&self, area: [f64; 4]) -> bool {
+        self.position[0] < area[0] + area[2]
+            && area[0] < self.position[0] + self.size[0]
+            && self.position[1] < area[1] + area[3]
+            && area[1] < self.position[1] + self.size[1]
+    }
+
+    pub fn render(&self, ctx: &Context, gl: &mut GlGraphics, offset: Vec2d) {
+        use graphics::*;
+
+        if self.collected {
+            return;
+        }
+
+        let rect = [self.position[0] + offset[0], self.position[1] + offset[1], self.size[0], self.size[1]];
+        ellipse(colors::GOLD, rect, ctx.transform, gl);
+    }
+}
@@ -0,0 +1,95 @@
+use opengl_graphics::GlGraphics;
+use graphics::Context;
+use graphics::math::*;
+
+use super::moving_object::MovingObject;
+use super::map::Map;
+use super::colors;
+use super::config;
+
+// Projectiles live in the level's objects so the collider finds what they hit, but they fly
+// straight instead of using the character physics.
+pub struct Projectile {
+    pub object_id: String,
+    pub damage: i32,
+    pub finished: bool,
+    lifetime: f64,
+    bounces: u32
+}
+
+impl Projectile {
+    pub fn new(object_id: String, bounces: u32) -> Projectile {
+        Projectile {
+            object_id,
+            damage: config::PROJECTILE_DAMAGE,
+            finished: false,
+            lifetime: config::PROJECTILE_LIFETIME,
+            bounces
+        }
+    }
+
+    pub fn object(&self, center: Vec2d, direction: f64) -> MovingObject {
+        let size = [config::PROJECTILE_SIZE, config::PROJECTILE_SIZE];
+        let mut object = MovingObject::new(
+            sub(center, mul_scalar(size, 0.5)),
+            size,
+            [0.0, 0.0],
+            0.0,
+            config::PROJECTILE_SPEED,
+            0.0,
+            self.object_id.clone());
+        object.speed = [config::PROJECTILE_SPEED * direction, 0.0];
+        object.aabb.center = center;
+        object
+    }
+
+    pub fn update(&mut self, delta: f64, map: &Map, object: &mut MovingObject) {
+        self.lifetime -= delta;
+        if self.lifetime <= 0.0 {
+            self.finished = true;
+            return;
+        }
+
+        object.old_position = object.position;
+
+        // Each axis moves on its own, so a projectile hitting a wall still keeps its vertical speed.
+        for axis in 0..2 {
+            let mut moved = object.position;
+            moved[axis] += object.speed[axis] * delta;
+
+            if !self.hits_block(map, moved, object.aabb.half_size) {
+                object.position = moved;
+            } else if self.bounces > 0 {
+                self.bounces -= 1;
+                object.speed[axis] = -object.speed[axis];
+            } else {
+                self.finished = true;
+                return;
+            }
+        }
+
+        object.aabb.center = add(object.position, object.aabb_offset);
+    }
+
+    fn hits_block(&self, map: &Map, position: Vec2d, half_size: Vec2d) -> bool {
+        let size = mul_scalar(half_size, 2.0);
+        let corners = [
+            position,
+            [position[0] + size[0], position[1]],
+            [position[0], position[1] + size[1]],
+            add(position, size)
+        ];
+
+        corners.iter().any(|corner| {
+            let tile = map.get_map_tile_in_point(*corner);
+            map.is_obstacle(tile.x, tile.y)
+        })
+    }
+
+    pub fn render(&self, ctx: &Context, gl: &mut GlGraphics, object: &MovingObject) {
+        use graphics::*;
+
+        let size = mul_scalar(object.aabb.half_size, 2.0);
+        ellipse(colors::PROJECTILE, [object.position[0], object.position[1], size[0], size[1]], ctx.transform, gl);
+    }
+}
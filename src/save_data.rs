@@ -0,0 +1,258 @@
+use serde::{Serialize, Deserialize};
+use serde_yaml::{Mapping, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::config;
+
+pub static SAVE_VERSION: u64 = 2;
+
+// MIGRATIONS[i] upgrades a save from version i + 1 to version i + 2.
+static MIGRATIONS: &[fn(&mut Mapping)] = &[add_high_scores];
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Checkpoint {
+    pub level: String,
+    pub index: usize,
+    pub position: [f64; 2]
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct HighScore {
+    pub name: String,
+    pub score: i32,
+    pub time: f64
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SaveData {
+    pub version: u64,
+    pub username: String,
+    pub unlocked_levels: Vec<String>,
+    pub best_scores: HashMap<String, i32>,
+    pub checkpoint: Option<Checkpoint>,
+    pub high_scores: HashMap<String, Vec<HighScore>>,
+    // Set when an unreadable save couldn't be moved aside, so it isn't written over.
+    #[serde(skip)]
+    read_only: bool
+}
+
+impl SaveData {
+    pub fn new() -> SaveData {
+        SaveData {
+            version: SAVE_VERSION,
+            username: String::from("unknown"),
+            unlocked_levels: vec![config::FIRST_LEVEL.to_string()],
+            best_scores: HashMap::new(),
+            checkpoint: None,
+            high_scores: HashMap::new(),
+            read_only: false
+        }
+    }
+
+    pub fn add_high_score(&mut self, level: &str, entry: HighScore) {
+        let best = self.best_scores.entry(level.to_string()).or_insert(entry.score);
+        *best = (*best).max(entry.score);
+
+        let table = self.high_scores.entry(level.to_string()).or_default();
+        table.push(entry);
+        table.sort_by(|a, b| b.score.cmp(&a.score).then(a.time.total_cmp(&b.time)));
+        table.truncate(config::HIGH_SCORE_ENTRIES);
+    }
+
+    pub fn level_high_scores(&self, level: &str) -> &[HighScore] {
+        self.high_scores.get(level).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    pub fn load() -> SaveData {
+        SaveData::load_from(&save_path())
+    }
+
+    // A save that can't be read is moved aside so starting over doesn't destroy it.
+    fn load_from(path: &Path) -> SaveData {
+        if !path.exists() {
+            return SaveData::new();
+        }
+
+        let error = match fs::read_to_string(path).map_err(|e| e.to_string()).and_then(|content| parse(&content)) {
+            Ok(data) => return data,
+            Err(error) => error
+        };
+        eprintln!("can't load save file {}: {}", path.display(), error);
+
+        let mut data = SaveData::new();
+        let backup = backup_path(path);
+        match fs::rename(path, &backup) {
+            Ok(()) => eprintln!("kept the old save as {}", backup.display()),
+            Err(error) => {
+                eprintln!("can't move the old save to {}: {}, progress won't be saved", backup.display(), error);
+                data.read_only = true;
+            }
+        }
+        data
+    }
+
+    pub fn save(&self) {
+        self.save_to(&save_path());
+    }
+
+    fn save_to(&self, path: &Path) {
+        if self.read_only {
+            return;
+        }
+
+        let content = serde_yaml::to_string(self).expect("can't serialize save data");
+        write_data_file(path, content);
+    }
+}
+
+pub fn save_path() -> PathBuf {
+    data_path(config::SAVE_FILE)
+}
+
+pub fn data_path(file: &str) -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(config::SAVE_DIRECTORY)
+        .join(file)
+}
+
+fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".bak");
+    path.with_file_name(name)
+}
+
+pub fn write_data_file(path: &Path, content: String) {
+    if let Some(dir) = path.parent() {
+        if let Err(error) = fs::create_dir_all(dir) {
+            eprintln!("can't create save directory {}: {}", dir.display(), error);
+            return;
+        }
+    }
+
+    if let Err(error) = fs::write(path, content) {
+        eprintln!("can't write save file {}: {}", path.display(), error);
+    }
+}
+
+fn parse(content: &str) -> Result<SaveData, String> {
+    let mut value: Value = serde_yaml::from_str(content).map_err(|e| e.to_string())?;
+    let mapping = value.as_mapping_mut().ok_or("save file is not a mapping")?;
+
+    let version_key = Value::from("version");
+    let version = mapping.get(&version_key).and_then(Value::as_u64).ok_or("save file has no version")?;
+
+    if version == 0 || version > SAVE_VERSION {
+        return Err(format!("unsupported save version {}", version));
+    }
+
+    for migration in MIGRATIONS.iter().skip(version as usize - 1) {
+        migration(mapping);
+    }
+    mapping.insert(version_key, Value::from(SAVE_VERSION));
+
+    serde_yaml::from_value(value).map_err(|e| e.to_string())
+}
+
+fn add_high_scores(save: &mut Mapping) {
+    save.insert(Value::from("high_scores"), Value::Mapping(Mapping::new()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VERSION_1: &str = "
+version: 1
+username: player
+unlocked_levels:
+  - level.map
+best_scores:
+  level.map: 1500
+checkpoint:
+  level: level.map
+  index: 2
+  position: [640.0, 300.0]
+";
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rusty_platformer_save_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir.join("save.yaml")
+    }
+
+    #[test]
+    fn every_version_has_a_migration() {
+        assert_eq!(MIGRATIONS.len() as u64, SAVE_VERSION - 1);
+    }
+
+    #[test]
+    fn parse_reads_a_current_save() {
+        let mut data = SaveData::new();
+        data.username = "player".to_string();
+        data.add_high_score("level.map", HighScore { name: "player".to_string(), score: 900, time: 42.5 });
+
+        let parsed = parse(&serde_yaml::to_string(&data).unwrap()).unwrap();
+
+        assert_eq!(parsed.version, SAVE_VERSION);
+        assert_eq!(parsed.username, "player");
+        assert_eq!(parsed.best_scores["level.map"], 900);
+        assert_eq!(parsed.level_high_scores("level.map")[0].time, 42.5);
+    }
+
+    #[test]
+    fn parse_migrates_version_1() {
+        let parsed = parse(VERSION_1).unwrap();
+
+        assert_eq!(parsed.version, SAVE_VERSION);
+        assert_eq!(parsed.username, "player");
+        assert_eq!(parsed.best_scores["level.map"], 1500);
+        assert_eq!(parsed.checkpoint.unwrap().index, 2);
+        assert!(parsed.high_scores.is_empty());
+    }
+
+    #[test]
+    fn add_high_scores_adds_an_empty_table() {
+        let mut save = Mapping::new();
+        save.insert(Value::from("version"), Value::from(1));
+
+        add_high_scores(&mut save);
+
+        assert_eq!(save.get(&Value::from("high_scores")), Some(&Value::Mapping(Mapping::new())));
+    }
+
+    #[test]
+    fn parse_rejects_unsupported_saves() {
+        assert!(parse("version: 0").is_err());
+        assert!(parse(&format!("version: {}", SAVE_VERSION + 1)).is_err());
+        assert!(parse("username: player").is_err());
+        assert!(parse("- not a mapping").is_err());
+        assert!(parse("version: 1\nusername: [").is_err());
+    }
+
+    #[test]
+    fn unreadable_save_is_kept_as_backup() {
+        let path = temp_path("backup");
+        fs::write(&path, "version: 99\n").unwrap();
+
+        let data = SaveData::load_from(&path);
+        data.save_to(&path);
+
+        assert_eq!(fs::read_to_string(backup_path(&path)).unwrap(), "version: 99\n");
+        assert_eq!(parse(&fs::read_to_string(&path).unwrap()).unwrap().version, SAVE_VERSION);
+    }
+
+    #[test]
+    fn read_only_save_is_not_written() {
+        let path = temp_path("read_only");
+        let mut data = SaveData::new();
+        data.read_only = true;
+
+        data.save_to(&path);
+
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,159 @@
+use opengl_graphics::{GlGraphics, GlyphCache};
+use graphics::Context;
+use serde::{Serialize, Deserialize};
+use std::fs;
+use std::path::PathBuf;
+
+use super::save_data;
+use super::textwriter::TextWriter;
+use super::colors;
+use super::config;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GhostFrame {
+    pub position: [f64; 2],
+    pub animation: String,
+    pub frame: usize,
+    pub mirrored: bool
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Run {
+    pub ticks: u64,
+    pub splits: Vec<u64>,
+    pub frames: Vec<GhostFrame>
+}
+
+pub struct SpeedrunTimer {
+    level: String,
+    ticks: u64,
+    splits: Vec<u64>,
+    frames: Vec<GhostFrame>,
+    best: Option<Run>,
+    valid: bool,
+    text_writer: TextWriter
+}
+
+impl SpeedrunTimer {
+    pub fn new(level: &str) -> SpeedrunTimer {
+        SpeedrunTimer {
+            level: level.to_string(),
+            ticks: 0,
+            splits: Vec::new(),
+            frames: Vec::new(),
+            best: load_best(level),
+            valid: true,
+            text_writer: TextWriter::new()
+        }
+    }
+
+    pub fn invalidate(&mut self) {
+        self.valid = false;
+    }
+
+    pub fn tick(&mut self, frame: GhostFrame) {
+        self.ticks += 1;
+        if self.valid {
+            self.frames.push(frame);
+        }
+    }
+
+    pub fn split(&mut self) {
+        self.splits.push(self.ticks);
+    }
+
+    pub fn finish(&mut self) {
+        if !self.valid {
+            return;
+        }
+
+        let is_best = self.best.as_ref().is_none_or(|best| self.ticks < best.ticks);
+        if !is_best {
+            return;
+        }
+
+        let run = Run {
+            ticks: self.ticks,
+            splits: self.splits.clone(),
+            frames: std::mem::take(&mut self.frames)
+        };
+
+        let content = serde_yaml::to_string(&run).expect("can't serialize run");
+        save_data::write_data_file(&best_run_path(&self.level), content);
+        self.best = Some(run);
+    }
+
+    pub fn ghost_frame(&self) -> Option<&GhostFrame> {
+        if !self.valid {
+            return None;
+        }
+
+        self.best
+            .as_ref()
+            .and_then(|best| best.frames.get(self.ticks.saturating_sub(1) as usize))
+    }
+
+    pub fn render(&mut self, ctx: &Context, gl: &mut GlGraphics, glyphs: &mut GlyphCache) {
+        let pos_x = ctx.get_view_size()[0] / 2.0 - config::HUD_FONT_SIZE as f64 * 3.0;
+        let pos_y = config::HUD_MARGIN + config::HUD_FONT_SIZE as f64;
+
+        self.text_writer.render_text(
+            ctx,
+            gl,
+            glyphs,
+            colors::BLACK,
+            config::HUD_FONT_SIZE,
+            pos_x,
+            pos_y,
+            &format_ticks(self.ticks));
+
+        if let Some((delta, color)) = self.last_split_delta() {
+            self.text_writer.render_text(
+                ctx,
+                gl,
+                glyphs,
+                color,
+                config::HUD_FONT_SIZE,
+                pos_x,
+                pos_y + config::HUD_FONT_SIZE as f64 * 1.5,
+                &delta);
+        }
+    }
+
+    fn last_split_delta(&self) -> Option<(String, [f32; 4])> {
+        let index = self.splits.len().checked_sub(1)?;
+        let best = self.best.as_ref()?.splits.get(index)?;
+
+        let delta = self.splits[index] as i64 - *best as i64;
+        let seconds = delta.abs() as f64 / config::UPS as f64;
+
+        if delta <= 0 {
+            Some((format!("-{:.2}", seconds), colors::GREEN))
+        } else {
+            Some((format!("+{:.2}", seconds), colors::RED))
+        }
+    }
+}
+
+pub fn format_ticks(ticks: u64) -> String {
+    let seconds = ticks as f64 / config::UPS as f64;
+    let minutes = (seconds / 60.0) as u32;
+    format!("{:02}:{:05.2}", minutes, seconds - minutes as f64 * 60.0)
+}
+
+fn best_run_path(level: &str) -> PathBuf {
+    save_data::data_path(&format!("{}.run.yaml", level))
+}
+
+fn load_best(level: &str) -> Option<Run> {
+    let path = best_run_path(level);
+    let content = fs::read_to_string(&path).ok()?;
+
+    match serde_yaml::from_str(&content) {
+        Ok(run) => Some(run),
+        Err(error) => {
+            eprintln!("can't load best run {}: {}", path.display(), error);
+            None
+        }
+    }
+}
@@ -0,0 +1,304 @@
+use graphics::ImageSize;
+use serde::Deserialize;
+use serde_yaml::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use std::rc::Rc;
+
+use super::animator::Frame;
+use super::texture_loader::{TextureLoader, TextureHandle};
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AtlasDescriptor {
+    Grid(GridDescriptor),
+    FrameList(FrameListDescriptor)
+}
+
+#[derive(Deserialize)]
+struct GridDescriptor {
+    image: String,
+    frame_width: f64,
+    frame_height: f64,
+    #[serde(default)]
+    margin: f64,
+    #[serde(default)]
+    spacing: f64,
+    #[serde(default)]
+    sequences: HashMap<String, GridSequence>
+}
+
+#[derive(Deserialize)]
+struct GridSequence {
+    start: usize,
+    count: usize,
+    duration: Option<f64>
+}
+
+// TexturePacker and Aseprite JSON exports, either as a hash keyed by frame name or as an array.
+#[derive(Deserialize)]
+struct FrameListDescriptor {
+    frames: Value,
+    meta: Meta
+}
+
+#[derive(Deserialize)]
+struct Meta {
+    image: String,
+    #[serde(default, rename = "frameTags")]
+    frame_tags: Vec<FrameTag>
+}
+
+#[derive(Deserialize)]
+struct FrameTag {
+    name: String,
+    from: usize,
+    to: usize
+}
+
+#[derive(Deserialize)]
+struct FrameEntry {
+    #[serde(default)]
+    filename: String,
+    frame: FrameRect,
+    duration: Option<f64>
+}
+
+#[derive(Deserialize)]
+struct FrameRect {
+    x: f64,
+    y: f64,
+    w: f64,
+    h: f64
+}
+
+pub struct AtlasFrame {
+    pub name: String,
+    pub source: [f64; 4],
+    pub duration: Option<f64>
+}
+
+pub struct SpriteAtlas {
+    texture: TextureHandle,
+    frames: Vec<AtlasFrame>,
+    sequences: HashMap<String, Vec<usize>>
+}
+
+impl SpriteAtlas {
+    pub fn load(tex_loader: &TextureLoader, descriptor_path: &str) -> SpriteAtlas {
+        let content = tex_loader.read_asset(descriptor_path);
+        let descriptor: AtlasDescriptor = serde_yaml::from_str(&content)
+            .unwrap_or_else(|e| panic!("can't parse atlas {}: {}", descriptor_path, e));
+
+        let directory = Path::new(descriptor_path).parent().unwrap_or_else(|| Path::new(""));
+
+        match descriptor {
+            AtlasDescriptor::Grid(grid) => {
+                let image = directory.join(&grid.image);
+                let texture = tex_loader.load_texture(image.to_str().unwrap());
+                SpriteAtlas::from_grid(texture, grid)
+            }
+            AtlasDescriptor::FrameList(list) => {
+                let image = directory.join(&list.meta.image);
+                let texture = tex_loader.load_texture(image.to_str().unwrap());
+                SpriteAtlas::from_frame_list(texture, list)
+            }
+        }
+    }
+
+    fn from_grid(texture: TextureHandle, grid: GridDescriptor) -> SpriteAtlas {
+        let (width, height) = texture.borrow().get_size();
+        let step_x = grid.frame_width + grid.spacing;
+        let step_y = grid.frame_height + grid.spacing;
+        let columns = ((width as f64 - grid.margin * 2.0 + grid.spacing) / step_x) as usize;
+        let rows = ((height as f64 - grid.margin * 2.0 + grid.spacing) / step_y) as usize;
+
+        let mut frames = Vec::new();
+        for row in 0..rows {
+            for column in 0..columns {
+                frames.push(AtlasFrame {
+                    name: (row * columns + column).to_string(),
+                    source: [
+                        grid.margin + column as f64 * step_x,
+                        grid.margin + row as f64 * step_y,
+                        grid.frame_width,
+                        grid.frame_height],
+                    duration: None
+                });
+            }
+        }
+
+        let mut sequences = HashMap::new();
+        for (name, sequence) in grid.sequences {
+            let indices: Vec<usize> = (sequence.start..sequence.start + sequence.count)
+                .filter(|i| *i < frames.len())
+                .collect();
+
+            for i in indices.iter() {
+                frames[*i].duration = sequence.duration;
+            }
+            sequences.insert(name, indices);
+        }
+
+        SpriteAtlas { texture, frames, sequences }
+    }
+
+    fn from_frame_list(texture: TextureHandle, list: FrameListDescriptor) -> SpriteAtlas {
+        let entries: Vec<FrameEntry> = match list.frames {
+            Value::Mapping(mapping) => mapping
+                .into_iter()
+                .map(|(name, entry)| {
+                    let mut entry: FrameEntry = serde_yaml::from_value(entry).expect("invalid atlas frame");
+                    entry.filename = name.as_str().unwrap_or_default().to_string();
+                    entry
+                })
+                .collect(),
+            frames => serde_yaml::from_value(frames).expect("invalid atlas frame list")
+        };
+
+        let frames: Vec<AtlasFrame> = entries
+            .into_iter()
+            .map(|entry| AtlasFrame {
+                name: entry.filename,
+                source: [entry.frame.x, entry.frame.y, entry.frame.w, entry.frame.h],
+                duration: entry.duration.map(|milliseconds| milliseconds / 1000.0)
+            })
+            .collect();
+
+        let sequences = list.meta.frame_tags
+            .into_iter()
+            .map(|tag| (tag.name, (tag.from..=tag.to).filter(|i| *i < frames.len()).collect()))
+            .collect();
+
+        SpriteAtlas { texture, frames, sequences }
+    }
+
+    // Frames of a tagged sequence, falling back to every frame named like "run 0", "run_1.png" or "run2".
+    pub fn sequence(&self, name: &str) -> Vec<&AtlasFrame> {
+        match self.sequences.get(name) {
+            Some(indices) => indices.iter().map(|i| &self.frames[*i]).collect(),
+            None => self.frames.iter().filter(|frame| SpriteAtlas::in_sequence(&frame.name, name)).collect()
+        }
+    }
+
+    fn in_sequence(frame_name: &str, name: &str) -> bool {
+        match frame_name.strip_prefix(name).map(|rest| rest.chars().next()) {
+            Some(None) => true,
+            Some(Some(c)) => c.is_ascii_digit() || " _-.(".contains(c),
+            None => false
+        }
+    }
+
+    pub fn frame(&self, atlas_frame: &AtlasFrame) -> Frame {
+        Frame {
+            texture: Rc::clone(&self.texture),
+            source: atlas_frame.source
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    // Enemy/idle-sheet.png is 576x64.
+    fn loader() -> TextureLoader {
+        TextureLoader::headless(Rc::new(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("assets")))
+    }
+
+    fn sources(atlas: &SpriteAtlas, sequence: &str) -> Vec<[f64; 4]> {
+        atlas.sequence(sequence).iter().map(|frame| frame.source).collect()
+    }
+
+    fn durations(atlas: &SpriteAtlas, sequence: &str) -> Vec<Option<f64>> {
+        atlas.sequence(sequence).iter().map(|frame| frame.duration).collect()
+    }
+
+    #[test]
+    fn loads_a_grid_atlas_descriptor() {
+        let atlas = SpriteAtlas::load(&loader(), "Enemy/enemy.atlas.yaml");
+
+        assert_eq!(atlas.frames.len(), 9);
+        assert_eq!(atlas.sequence("idle").len(), 9);
+        assert_eq!(sources(&atlas, "idle")[8], [512.0, 0.0, 64.0, 64.0]);
+    }
+
+    #[test]
+    fn character_atlases_have_all_their_frames() {
+        let loader = loader();
+        for (name, count) in [("idle", 10), ("run", 8), ("jump", 10), ("attack", 5), ("climb", 4)].iter() {
+            let atlas = SpriteAtlas::load(&loader, &format!("Character/{}.atlas.yaml", name));
+            assert_eq!(atlas.sequence(name).len(), *count, "{}", name);
+        }
+    }
+
+    #[test]
+    fn grid_sequences_stay_inside_the_sheet() {
+        let texture = loader().load_texture("Enemy/idle-sheet.png");
+        let grid: GridDescriptor = serde_yaml::from_str("
+image: idle-sheet.png
+frame_width: 60
+frame_height: 60
+margin: 2
+spacing: 4
+sequences:
+  walk:
+    start: 1
+    count: 2
+    duration: 0.2
+  tail:
+    start: 7
+    count: 5
+").unwrap();
+
+        let atlas = SpriteAtlas::from_grid(texture, grid);
+
+        assert_eq!(atlas.frames.len(), 9);
+        assert_eq!(sources(&atlas, "walk"), vec![[66.0, 2.0, 60.0, 60.0], [130.0, 2.0, 60.0, 60.0]]);
+        assert_eq!(durations(&atlas, "walk"), vec![Some(0.2), Some(0.2)]);
+        assert_eq!(atlas.sequence("tail").len(), 2);
+        assert!(atlas.sequence("missing").is_empty());
+    }
+
+    #[test]
+    fn frame_lists_use_tags_and_millisecond_durations() {
+        let texture = loader().load_texture("Enemy/idle-sheet.png");
+        let list: FrameListDescriptor = serde_yaml::from_str(r#"{
+            "frames": [
+                {"filename": "idle 0", "frame": {"x": 0, "y": 0, "w": 64, "h": 64}, "duration": 100},
+                {"filename": "idle 1", "frame": {"x": 64, "y": 0, "w": 64, "h": 64}, "duration": 150},
+                {"filename": "hit 0", "frame": {"x": 128, "y": 0, "w": 32, "h": 64}}
+            ],
+            "meta": {"image": "idle-sheet.png", "frameTags": [{"name": "blink", "from": 1, "to": 2}]}
+        }"#).unwrap();
+
+        let atlas = SpriteAtlas::from_frame_list(texture, list);
+
+        assert_eq!(sources(&atlas, "blink"), vec![[64.0, 0.0, 64.0, 64.0], [128.0, 0.0, 32.0, 64.0]]);
+        assert_eq!(durations(&atlas, "idle"), vec![Some(0.1), Some(0.15)]);
+        assert_eq!(sources(&atlas, "hit"), vec![[128.0, 0.0, 32.0, 64.0]]);
+    }
+
+    #[test]
+    fn frame_hashes_are_named_by_their_keys() {
+        let texture = loader().load_texture("Enemy/idle-sheet.png");
+        let list: FrameListDescriptor = serde_yaml::from_str(r#"{
+            "frames": {
+                "run 0": {"frame": {"x": 0, "y": 0, "w": 64, "h": 64}},
+                "run 1": {"frame": {"x": 64, "y": 0, "w": 64, "h": 64}},
+                "runner_0.png": {"frame": {"x": 128, "y": 0, "w": 64, "h": 64}},
+                "runner_1.png": {"frame": {"x": 192, "y": 0, "w": 64, "h": 64}},
+                "run2": {"frame": {"x": 256, "y": 0, "w": 64, "h": 64}}
+            },
+            "meta": {"image": "idle-sheet.png"}
+        }"#).unwrap();
+
+        let atlas = SpriteAtlas::from_frame_list(texture, list);
+
+        assert_eq!(atlas.sequence("run").len(), 3);
+        assert_eq!(atlas.sequence("runner").len(), 2);
+        assert_eq!(atlas.frames[1].name, "run 1");
+        assert_eq!(durations(&atlas, "run"), vec![None, None, None]);
+    }
+}
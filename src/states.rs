@@ -1,6 +1,10 @@
 pub enum State<T> {
     Start(T),
+    Loading(T),
     Game(T),
     End(T),
+    Leaderboard(T),
+    Editor(T),
+    Quit,
     None
 }
\ No newline at end of file
@@ -1,23 +1,186 @@
+use graphics::ImageSize;
 use opengl_graphics::{Texture, TextureSettings};
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::rc::Rc;
 
+pub type TextureHandle = Rc<RefCell<LoadedTexture>>;
+
+// Without an OpenGL context there is nothing to upload to, headless textures only carry the
+// size of their image so sprite atlases still get their frames.
+pub enum LoadedTexture {
+    Gl(Texture),
+    Headless(u32, u32)
+}
+
+impl LoadedTexture {
+    // The texture to draw with, `None` when running headless.
+    pub fn gl(&self) -> Option<&Texture> {
+        match self {
+            LoadedTexture::Gl(texture) => Some(texture),
+            LoadedTexture::Headless(_, _) => None
+        }
+    }
+}
+
+impl ImageSize for LoadedTexture {
+    fn get_size(&self) -> (u32, u32) {
+        match self {
+            LoadedTexture::Gl(texture) => texture.get_size(),
+            LoadedTexture::Headless(width, height) => (*width, *height)
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Manifest {
+    textures: Vec<String>
+}
+
 pub struct TextureLoader {
-    assets_path: Rc<PathBuf>
+    assets_path: Rc<PathBuf>,
+    cache: RefCell<HashMap<String, TextureHandle>>,
+    headless: bool
 }
 
 impl TextureLoader {
     pub fn new(assets_path: Rc<PathBuf>) -> TextureLoader {
         TextureLoader {
-            assets_path: assets_path
+            assets_path: assets_path,
+            cache: RefCell::new(HashMap::new()),
+            headless: false
+        }
+    }
+
+    pub fn headless(assets_path: Rc<PathBuf>) -> TextureLoader {
+        TextureLoader {
+            headless: true,
+            ..TextureLoader::new(assets_path)
+        }
+    }
+
+    pub fn read_asset(&self, path: &str) -> String {
+        let asset_path = self.assets_path.join(path);
+        std::fs::read_to_string(&asset_path)
+            .unwrap_or_else(|e| panic!("can't read asset {}: {}", asset_path.display(), e))
+    }
+
+    pub fn load_texture(&self, path: &str) -> TextureHandle {
+        if let Some(texture) = self.cache.borrow().get(path) {
+            return Rc::clone(texture);
+        }
+
+        let texture = self.read_texture(path)
+            .unwrap_or_else(|e| panic!("can't load texture {}: {}", path, e));
+        let texture = Rc::new(RefCell::new(texture));
+
+        self.cache.borrow_mut().insert(path.to_string(), Rc::clone(&texture));
+        texture
+    }
+
+    // Replaces the contents of already cached textures, so every holder of the handle sees the new image.
+    pub fn reload(&self, changed: &[String]) {
+        if self.headless {
+            return;
+        }
+
+        let cache = self.cache.borrow();
+
+        for path in changed {
+            if let Some(texture) = cache.get(path) {
+                match self.read_texture(path) {
+                    Ok(reloaded) => *texture.borrow_mut() = reloaded,
+                    Err(error) => eprintln!("can't reload texture {}: {}", path, error)
+                }
+            }
         }
     }
-    pub fn load_texture(&self, path: &str) -> Texture {
-         let texture_path = self.assets_path.join(path);
 
-         Texture::from_path(
-            texture_path,
+    fn read_texture(&self, path: &str) -> Result<LoadedTexture, String> {
+        if self.headless {
+            let (width, height) = image::image_dimensions(self.assets_path.join(path))
+                .map_err(|e| e.to_string())?;
+            return Ok(LoadedTexture::Headless(width, height));
+        }
+
+        Texture::from_path(
+            self.assets_path.join(path),
             &TextureSettings::new()
-        ).unwrap()
+        ).map(LoadedTexture::Gl)
+    }
+
+    pub fn load_manifest(&self, path: &str) -> Vec<String> {
+        let manifest: Manifest = serde_yaml::from_str(&self.read_asset(path))
+            .unwrap_or_else(|e| panic!("can't parse manifest {}: {}", path, e));
+        manifest.textures
+    }
+
+    // Drops cached textures that are no longer referenced outside of the cache.
+    pub fn evict_unused(&self) -> usize {
+        let mut cache = self.cache.borrow_mut();
+        let before = cache.len();
+        cache.retain(|_, texture| Rc::strong_count(texture) > 1);
+        before - cache.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::config;
+    use super::super::sprite_atlas::SpriteAtlas;
+
+    fn assets() -> Rc<PathBuf> {
+        Rc::new(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("assets"))
+    }
+
+    #[test]
+    fn headless_textures_only_know_their_size() {
+        let loader = TextureLoader::headless(assets());
+        let texture = loader.load_texture("Character/Idle (1).png");
+
+        let (width, height) = texture.borrow().get_size();
+        assert!(width > 0 && height > 0);
+        assert!(texture.borrow().gl().is_none());
+        assert!(Rc::ptr_eq(&texture, &loader.load_texture("Character/Idle (1).png")));
+    }
+
+    fn atlas_descriptors(dir: &std::path::Path, relative: &str, found: &mut Vec<String>) {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let entry = entry.unwrap();
+            let name = format!("{}{}", relative, entry.file_name().to_string_lossy());
+            if entry.file_type().unwrap().is_dir() {
+                atlas_descriptors(&entry.path(), &format!("{}/", name), found);
+            } else if name.ends_with(".atlas.yaml") {
+                found.push(name);
+            }
+        }
+    }
+
+    #[test]
+    fn manifest_preloads_every_atlas_sheet() {
+        let loader = TextureLoader::headless(assets());
+        let manifest = loader.load_manifest(config::ASSET_MANIFEST);
+
+        let mut descriptors = Vec::new();
+        atlas_descriptors(&assets(), "", &mut descriptors);
+        assert!(!descriptors.is_empty());
+        for descriptor in descriptors.iter() {
+            SpriteAtlas::load(&loader, descriptor);
+        }
+
+        for path in loader.cache.borrow().keys() {
+            assert!(manifest.contains(path), "{} is missing from the manifest", path);
+        }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn headless_textures_can_be_dropped() {
+        let loader = TextureLoader::headless(assets());
+        drop(loader.load_texture("Character/Idle (1).png"));
+
+        assert_eq!(loader.evict_unused(), 1);
+    }
+}
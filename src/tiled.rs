@@ -0,0 +1,613 @@
+use roxmltree::Node;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
+
+use super::map::{TileType, Tileset, TileLayer, LayerKind};
+use super::map_loader::{Level, LevelObject, ObjectKind};
+
+// The upper bits of a Tiled gid store flip flags, which we don't support.
+const GID_MASK: u32 = 0x1FFF_FFFF;
+
+struct TiledTileset {
+    tileset: Tileset,
+    collision: HashMap<u32, TileType>
+}
+
+struct TiledObject {
+    name: String,
+    class: String,
+    bounds: [f64; 4],
+    properties: HashMap<String, String>
+}
+
+enum TiledLayer {
+    Tiles {
+        name: String,
+        properties: HashMap<String, String>,
+        data: Vec<u32>
+    },
+    Objects(Vec<TiledObject>)
+}
+
+struct TiledMap {
+    width: usize,
+    height: usize,
+    tile_width: f64,
+    tilesets: Vec<TiledTileset>,
+    layers: Vec<TiledLayer>
+}
+
+#[derive(Deserialize)]
+struct JsonMap {
+    width: usize,
+    height: usize,
+    tilewidth: f64,
+    #[serde(default)]
+    infinite: bool,
+    tilesets: Vec<JsonTileset>,
+    layers: Vec<JsonLayer>
+}
+
+#[derive(Deserialize)]
+struct JsonTileset {
+    #[serde(default)]
+    firstgid: u32,
+    source: Option<String>,
+    image: Option<String>,
+    tilewidth: Option<f64>,
+    tileheight: Option<f64>,
+    #[serde(default)]
+    margin: f64,
+    #[serde(default)]
+    spacing: f64,
+    #[serde(default)]
+    tiles: Vec<JsonTile>
+}
+
+#[derive(Deserialize)]
+struct JsonTile {
+    id: u32,
+    #[serde(default)]
+    properties: Vec<JsonProperty>
+}
+
+#[derive(Deserialize)]
+struct JsonProperty {
+    name: String,
+    value: serde_json::Value
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum JsonLayer {
+    TileLayer {
+        name: String,
+        #[serde(default)]
+        data: serde_json::Value,
+        #[serde(default)]
+        properties: Vec<JsonProperty>
+    },
+    ObjectGroup {
+        #[serde(default)]
+        objects: Vec<JsonObject>
+    },
+    #[serde(other)]
+    Other
+}
+
+#[derive(Deserialize)]
+struct JsonObject {
+    #[serde(default)]
+    name: String,
+    #[serde(default, rename = "type")]
+    kind: String,
+    #[serde(default)]
+    class: String,
+    x: f64,
+    y: f64,
+    #[serde(default)]
+    width: f64,
+    #[serde(default)]
+    height: f64,
+    #[serde(default)]
+    properties: Vec<JsonProperty>
+}
+
+// `read` loads other assets, like external tilesets, by their path relative to the assets folder.
+pub fn import_json(path: &str, content: &str, tile_size: f64, read: &dyn Fn(&str) -> Result<String, String>) -> Result<Level, String> {
+    let map: JsonMap = serde_json::from_str(content).map_err(|e| e.to_string())?;
+    if map.infinite {
+        return Err("infinite maps are not supported".to_string());
+    }
+
+    let mut tilesets = Vec::new();
+    for entry in map.tilesets {
+        let tileset = match &entry.source {
+            Some(source) => {
+                let source = resolve(path, source);
+                let mut external: JsonTileset = serde_json::from_str(&read(&source)?)
+                    .map_err(|e| format!("{}: {}", source, e))?;
+                external.firstgid = entry.firstgid;
+                json_tileset(&source, external)?
+            },
+            None => json_tileset(path, entry)?
+        };
+        tilesets.push(tileset);
+    }
+
+    let mut layers = Vec::new();
+    for layer in map.layers {
+        match layer {
+            JsonLayer::TileLayer { name, data, properties } => {
+                let data = data
+                    .as_array()
+                    .ok_or(format!("layer {} must use csv encoding", name))?
+                    .iter()
+                    .map(|gid| gid.as_u64().map(|gid| gid as u32).ok_or(format!("invalid tile id in layer {}", name)))
+                    .collect::<Result<Vec<u32>, String>>()?;
+
+                layers.push(TiledLayer::Tiles { name, properties: json_properties(properties), data });
+            },
+            JsonLayer::ObjectGroup { objects } => {
+                let objects = objects
+                    .into_iter()
+                    .map(|object| TiledObject {
+                        name: object.name,
+                        class: if object.class.is_empty() { object.kind } else { object.class },
+                        bounds: [object.x, object.y, object.width, object.height],
+                        properties: json_properties(object.properties)
+                    })
+                    .collect();
+
+                layers.push(TiledLayer::Objects(objects));
+            },
+            JsonLayer::Other => {}
+        }
+    }
+
+    to_level(TiledMap {
+        width: map.width,
+        height: map.height,
+        tile_width: map.tilewidth,
+        tilesets,
+        layers
+    }, tile_size)
+}
+
+pub fn import_tmx(path: &str, content: &str, tile_size: f64, read: &dyn Fn(&str) -> Result<String, String>) -> Result<Level, String> {
+    let document = roxmltree::Document::parse(content).map_err(|e| e.to_string())?;
+    let root = document.root_element();
+    if attribute_or(root, "infinite", 0)? != 0 {
+        return Err("infinite maps are not supported".to_string());
+    }
+
+    let mut tilesets = Vec::new();
+    let mut layers = Vec::new();
+
+    for node in root.children().filter(|node| node.is_element()) {
+        match node.tag_name().name() {
+            "tileset" => {
+                let first_gid = attribute(node, "firstgid")?;
+                let tileset = match node.attribute("source") {
+                    Some(source) => {
+                        let source = resolve(path, source);
+                        let content = read(&source)?;
+                        let external = roxmltree::Document::parse(&content).map_err(|e| format!("{}: {}", source, e))?;
+                        tmx_tileset(&source, external.root_element(), first_gid)?
+                    },
+                    None => tmx_tileset(path, node, first_gid)?
+                };
+                tilesets.push(tileset);
+            },
+            "layer" => layers.push(tmx_tile_layer(node)?),
+            "objectgroup" => layers.push(tmx_object_layer(node)?),
+            _ => {}
+        }
+    }
+
+    to_level(TiledMap {
+        width: attribute(root, "width")?,
+        height: attribute(root, "height")?,
+        tile_width: attribute(root, "tilewidth")?,
+        tilesets,
+        layers
+    }, tile_size)
+}
+
+fn json_tileset(path: &str, entry: JsonTileset) -> Result<TiledTileset, String> {
+    let image = entry.image.ok_or("image collection tilesets are not supported")?;
+
+    let mut collision = HashMap::new();
+    for tile in entry.tiles {
+        if let Some(value) = json_properties(tile.properties).get("collision") {
+            collision.insert(tile.id, parse_collision(value)?);
+        }
+    }
+
+    Ok(TiledTileset {
+        tileset: Tileset {
+            image: resolve(path, &image),
+            first_gid: entry.firstgid,
+            tile_width: entry.tilewidth,
+            tile_height: entry.tileheight,
+            margin: entry.margin,
+            spacing: entry.spacing
+        },
+        collision
+    })
+}
+
+fn json_properties(properties: Vec<JsonProperty>) -> HashMap<String, String> {
+    properties
+        .into_iter()
+        .map(|property| {
+            let value = match property.value {
+                serde_json::Value::String(value) => value,
+                value => value.to_string()
+            };
+            (property.name, value)
+        })
+        .collect()
+}
+
+fn tmx_tileset(path: &str, node: Node, first_gid: u32) -> Result<TiledTileset, String> {
+    let image = node
+        .children()
+        .find(|child| child.has_tag_name("image"))
+        .and_then(|image| image.attribute("source"))
+        .ok_or("image collection tilesets are not supported")?;
+
+    let mut collision = HashMap::new();
+    for tile in node.children().filter(|child| child.has_tag_name("tile")) {
+        if let Some(value) = tmx_properties(tile).get("collision") {
+            collision.insert(attribute(tile, "id")?, parse_collision(value)?);
+        }
+    }
+
+    Ok(TiledTileset {
+        tileset: Tileset {
+            image: resolve(path, image),
+            first_gid,
+            tile_width: Some(attribute(node, "tilewidth")?),
+            tile_height: Some(attribute(node, "tileheight")?),
+            margin: attribute_or(node, "margin", 0.0)?,
+            spacing: attribute_or(node, "spacing", 0.0)?
+        },
+        collision
+    })
+}
+
+fn tmx_tile_layer(node: Node) -> Result<TiledLayer, String> {
+    let name = node.attribute("name").unwrap_or_default().to_string();
+    let data = node
+        .children()
+        .find(|child| child.has_tag_name("data"))
+        .ok_or(format!("layer {} has no data", name))?;
+
+    let data = match data.attribute("encoding") {
+        Some("csv") => data
+            .text()
+            .unwrap_or_default()
+            .split(',')
+            .map(|gid| gid.trim().parse().map_err(|_| format!("invalid tile id in layer {}", name)))
+            .collect::<Result<Vec<u32>, String>>()?,
+        None => data
+            .children()
+            .filter(|child| child.has_tag_name("tile"))
+            .map(|tile| attribute_or(tile, "gid", 0))
+            .collect::<Result<Vec<u32>, String>>()?,
+        Some(_) => return Err(format!("layer {} must use csv or xml encoding", name))
+    };
+
+    Ok(TiledLayer::Tiles { properties: tmx_properties(node), name, data })
+}
+
+fn tmx_object_layer(node: Node) -> Result<TiledLayer, String> {
+    let mut objects = Vec::new();
+
+    for object in node.children().filter(|child| child.has_tag_name("object")) {
+        let class = object.attribute("class").or(object.attribute("type")).unwrap_or_default();
+
+        objects.push(TiledObject {
+            name: object.attribute("name").unwrap_or_default().to_string(),
+            class: class.to_string(),
+            bounds: [
+                attribute(object, "x")?,
+                attribute(object, "y")?,
+                attribute_or(object, "width", 0.0)?,
+                attribute_or(object, "height", 0.0)?
+            ],
+            properties: tmx_properties(object)
+        });
+    }
+
+    Ok(TiledLayer::Objects(objects))
+}
+
+fn tmx_properties(node: Node) -> HashMap<String, String> {
+    node.children()
+        .filter(|child| child.has_tag_name("properties"))
+        .flat_map(|properties| properties.children().filter(|child| child.has_tag_name("property")))
+        .filter_map(|property| {
+            let value = property.attribute("value").or(property.text()).unwrap_or_default();
+            property.attribute("name").map(|name| (name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+fn attribute<T: FromStr>(node: Node, name: &str) -> Result<T, String> {
+    node.attribute(name)
+        .ok_or(format!("<{}> is missing {}", node.tag_name().name(), name))?
+        .parse()
+        .map_err(|_| format!("<{}> has an invalid {}", node.tag_name().name(), name))
+}
+
+fn attribute_or<T: FromStr>(node: Node, name: &str, default: T) -> Result<T, String> {
+    match node.attribute(name) {
+        Some(_) => attribute(node, name),
+        None => Ok(default)
+    }
+}
+
+fn parse_collision(value: &str) -> Result<TileType, String> {
+    match value.to_lowercase().as_str() {
+        "block" => Ok(TileType::Block),
+        "oneway" | "one_way" | "one-way" => Ok(TileType::OneWay),
+        "none" | "empty" => Ok(TileType::Empty),
+        "spring" => Ok(TileType::Spring),
+        "conveyor_left" | "conveyor-left" => Ok(TileType::ConveyorLeft),
+        "conveyor_right" | "conveyor-right" => Ok(TileType::ConveyorRight),
+        "ladder" => Ok(TileType::Ladder),
+        _ => Err(format!("unknown collision type {}", value))
+    }
+}
+
+fn parse_kind(value: &str) -> Result<LayerKind, String> {
+    match value.to_lowercase().as_str() {
+        "background" => Ok(LayerKind::Background),
+        "collision" => Ok(LayerKind::Collision),
+        "foreground" => Ok(LayerKind::Foreground),
+        _ => Err(format!("unknown layer kind {}", value))
+    }
+}
+
+fn parse_object_kind(value: &str) -> Option<ObjectKind> {
+    match value.to_lowercase().as_str() {
+        "spawn" | "player" => Some(ObjectKind::Spawn),
+        "enemy" => Some(ObjectKind::Enemy),
+        "pickup" => Some(ObjectKind::Pickup),
+        "trigger" => Some(ObjectKind::Trigger),
+        _ => None
+    }
+}
+
+// Layers without a "kind" property are drawn behind the collision layer until it
+// appears, and in front of everything after it.
+fn to_level(map: TiledMap, tile_size: f64) -> Result<Level, String> {
+    if map.width > i8::MAX as usize || map.height > i8::MAX as usize {
+        return Err(format!("maps can be at most {} tiles wide and high", i8::MAX));
+    }
+
+    let scale = tile_size / map.tile_width;
+    let mut tiles = vec![vec![TileType::Empty; map.width]; map.height];
+    let mut layers = Vec::new();
+    let mut objects = Vec::new();
+    let mut seen_collision = false;
+
+    for layer in map.layers {
+        match layer {
+            TiledLayer::Tiles { name, properties, data } => {
+                if data.len() != map.width * map.height {
+                    return Err(format!("layer {} doesn't match the map size", name));
+                }
+
+                let kind = match properties.get("kind") {
+                    Some(kind) => parse_kind(kind)?,
+                    None if name.eq_ignore_ascii_case("collision") => LayerKind::Collision,
+                    None if seen_collision => LayerKind::Foreground,
+                    None => LayerKind::Background
+                };
+
+                let gids: Vec<Vec<u32>> = data
+                    .chunks(map.width)
+                    .map(|row| row.iter().map(|gid| gid & GID_MASK).collect())
+                    .collect();
+
+                if kind == LayerKind::Collision {
+                    seen_collision = true;
+                    let default = match properties.get("collision") {
+                        Some(value) => parse_collision(value)?,
+                        None => TileType::Block
+                    };
+
+                    for (row, columns) in gids.iter().enumerate() {
+                        for (column, gid) in columns.iter().enumerate() {
+                            if *gid != 0 {
+                                tiles[row][column] = collision_of(&map.tilesets, *gid).unwrap_or(default);
+                            }
+                        }
+                    }
+                }
+
+                layers.push(TileLayer { name, kind, tiles: gids });
+            },
+            TiledLayer::Objects(tiled_objects) => {
+                for object in tiled_objects {
+                    let kind = match parse_object_kind(&object.class) {
+                        Some(kind) => kind,
+                        None => {
+                            eprintln!("ignoring object {} of unknown type {:?}", object.name, object.class);
+                            continue;
+                        }
+                    };
+
+                    let [x, y, width, height] = object.bounds;
+                    objects.push(LevelObject {
+                        kind,
+                        name: object.name,
+                        position: [x * scale, y * scale],
+                        size: [width * scale, height * scale],
+                        properties: object.properties
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(Level {
+        tiles,
+        layers: Some((map.tilesets.into_iter().map(|tileset| tileset.tileset).collect(), layers)),
+        objects
+    })
+}
+
+fn collision_of(tilesets: &[TiledTileset], gid: u32) -> Option<TileType> {
+    let tileset = tilesets
+        .iter()
+        .filter(|tileset| tileset.tileset.first_gid <= gid)
+        .max_by_key(|tileset| tileset.tileset.first_gid)?;
+
+    tileset.collision.get(&(gid - tileset.tileset.first_gid)).copied()
+}
+
+// Tiled stores paths relative to the file referencing them, we keep them relative to the assets folder.
+fn resolve(base: &str, relative: &str) -> String {
+    let joined = Path::new(base).parent().unwrap_or(Path::new("")).join(relative);
+
+    let mut resolved = PathBuf::new();
+    for component in joined.components() {
+        match component {
+            Component::ParentDir => { resolved.pop(); },
+            Component::Normal(part) => resolved.push(part),
+            _ => {}
+        }
+    }
+    resolved.to_string_lossy().replace('\\', "/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    // tests/fixtures/tiled holds the same 4x3 level saved as JSON and TMX, with 16px tiles and
+    // an external tileset of springs.
+    fn fixture(path: &str) -> Result<String, String> {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/tiled");
+        fs::read_to_string(fixtures.join(path)).map_err(|e| format!("{}: {}", path, e))
+    }
+
+    fn import(path: &str, content: &str) -> Result<Level, String> {
+        if path.ends_with(".tmx") {
+            import_tmx(path, content, 24.0, &fixture)
+        } else {
+            import_json(path, content, 24.0, &fixture)
+        }
+    }
+
+    fn rows(level: &Level) -> Vec<String> {
+        level.tiles.iter().map(|row| row.iter().map(|tile| tile.to_char()).collect()).collect()
+    }
+
+    fn check_level(path: &str) {
+        let level = import(path, &fixture(path).unwrap()).unwrap();
+
+        // Flip flags are ignored, tiles without a collision property are blocks.
+        assert_eq!(rows(&level), vec!["EEEE", "BOHE", "BSBB"]);
+
+        let (tilesets, layers) = level.layers.as_ref().unwrap();
+        assert_eq!(tilesets.len(), 2);
+        assert_eq!(tilesets[0].image, "images/tiles.png");
+        assert_eq!((tilesets[0].first_gid, tilesets[0].margin, tilesets[0].spacing), (1, 1.0, 2.0));
+        assert_eq!(tilesets[0].tile_width, Some(16.0));
+        assert_eq!(tilesets[1].image, "images/springs.png");
+        assert_eq!(tilesets[1].first_gid, 10);
+
+        let names: Vec<&str> = layers.iter().map(|layer| layer.name.as_str()).collect();
+        assert_eq!(names, vec!["sky", "Collision", "grass", "hills"]);
+        assert!(layers[0].kind == LayerKind::Background);
+        assert!(layers[1].kind == LayerKind::Collision);
+        assert!(layers[2].kind == LayerKind::Foreground);
+        assert!(layers[3].kind == LayerKind::Background);
+        assert_eq!(layers[0].tiles, vec![vec![4; 4], vec![4; 4], vec![0; 4]]);
+        assert_eq!(layers[1].tiles[2], vec![1, 10, 4, 1]);
+
+        // Objects are scaled from 16px to 24px tiles, the camera isn't a known kind.
+        assert_eq!(level.objects.len(), 3);
+        let spawn = level.objects_of(ObjectKind::Spawn).next().unwrap();
+        assert_eq!((spawn.name.as_str(), spawn.position), ("start", [24.0, 12.0]));
+        let enemy = level.objects_of(ObjectKind::Enemy).next().unwrap();
+        assert_eq!(enemy.position, [48.0, 24.0]);
+        let pickup = level.objects_of(ObjectKind::Pickup).next().unwrap();
+        assert_eq!((pickup.position, pickup.size), ([72.0, 0.0], [12.0, 12.0]));
+        assert_eq!(pickup.properties["value"], "50");
+    }
+
+    #[test]
+    fn imports_json_maps() {
+        check_level("maps/level.json");
+    }
+
+    #[test]
+    fn imports_tmx_maps() {
+        check_level("maps/level.tmx");
+    }
+
+    #[test]
+    fn parses_collision_names() {
+        assert!(parse_collision("Block") == Ok(TileType::Block));
+        assert!(parse_collision("one_way") == Ok(TileType::OneWay));
+        assert!(parse_collision("none") == Ok(TileType::Empty));
+        assert!(parse_collision("conveyor-left") == Ok(TileType::ConveyorLeft));
+        assert!(parse_collision("conveyor_right") == Ok(TileType::ConveyorRight));
+        assert!(parse_collision("LADDER") == Ok(TileType::Ladder));
+        assert!(parse_collision("lava").is_err());
+    }
+
+    #[test]
+    fn resolves_paths_against_the_referencing_file() {
+        assert_eq!(resolve("maps/level.json", "../tilesets/springs.tsj"), "tilesets/springs.tsj");
+        assert_eq!(resolve("level.tmx", "tiles.png"), "tiles.png");
+        assert_eq!(resolve("maps/a/level.tmx", "../../tiles.png"), "tiles.png");
+    }
+
+    fn import_error(path: &str, from: &str, to: &str) -> String {
+        let content = fixture(path).unwrap();
+        assert!(content.contains(from), "{} doesn't contain {}", path, from);
+        match import(path, &content.replacen(from, to, 1)) {
+            Ok(_) => panic!("{} imported with {} replaced by {}", path, from, to),
+            Err(error) => error
+        }
+    }
+
+    #[test]
+    fn json_errors() {
+        let path = "maps/level.json";
+        assert!(import(path, "{").is_err());
+        assert!(import_error(path, r#""infinite": false"#, r#""infinite": true"#).contains("infinite"));
+        assert!(import_error(path, "../tilesets/springs.tsj", "../tilesets/missing.tsj").contains("missing.tsj"));
+        assert!(import_error(path, r#""value": "ladder""#, r#""value": "lava""#).contains("lava"));
+        assert!(import_error(path, r#""image": "../images/tiles.png","#, "").contains("image collection"));
+        assert!(import_error(path, "[4, 4, 4, 4, 4, 4, 4, 4, 0, 0, 0, 0]", r#""AAAA""#).contains("csv"));
+        assert!(import_error(path, "[4, 4, 4, 4, 4, 4, 4, 4, 0, 0, 0, 0]", "[4, -4]").contains("invalid tile id"));
+        assert!(import_error(path, "[4, 4, 4, 4, 4, 4, 4, 4, 0, 0, 0, 0]", "[4, 4]").contains("map size"));
+        assert!(import_error(path, r#""value": "background""#, r#""value": "sideground""#).contains("sideground"));
+    }
+
+    #[test]
+    fn tmx_errors() {
+        let path = "maps/level.tmx";
+        assert!(import(path, "<map").is_err());
+        assert!(import_error(path, r#"infinite="0""#, r#"infinite="1""#).contains("infinite"));
+        assert!(import_error(path, "../tilesets/springs.tsx", "../tilesets/missing.tsx").contains("missing.tsx"));
+        assert!(import_error(path, r#"value="one-way""#, r#"value="lava""#).contains("lava"));
+        assert!(import_error(path, r#"<image source="../images/tiles.png" width="64" height="64"/>"#, "").contains("image collection"));
+        assert!(import_error(path, r#"<data encoding="csv">"#, r#"<data encoding="base64">"#).contains("csv or xml"));
+        assert!(import_error(path, "2147483649,10,4,1", "2147483649,ten,4,1").contains("invalid tile id"));
+        assert!(import_error(path, "2147483649,10,4,1", "2147483649,10").contains("map size"));
+        assert!(import_error(path, r#"width="4" height="3" tilewidth"#, r#"width="200" height="3" tilewidth"#).contains("at most"));
+        assert!(import_error(path, r#"<object id="2" name="guard" type="enemy" x="32""#, r#"<object id="2" name="guard" type="enemy" x="far""#).contains("invalid x"));
+    }
+}
@@ -0,0 +1,182 @@
+use opengl_graphics::{GlGraphics, GlyphCache};
+use piston::input::{UpdateArgs, Button};
+use graphics::Context;
+
+use super::gamestate::GameState;
+use super::gamedata::GameData;
+use super::states::State;
+use super::menu::{Menu, MenuInput};
+use super::textwriter::TextWriter;
+use super::colors;
+use super::config;
+
+const NEW_GAME: usize = 0;
+const CONTINUE: usize = 1;
+const HIGH_SCORES: usize = 2;
+const OPTIONS: usize = 3;
+const QUIT: usize = 4;
+
+const SHOW_FPS: usize = 0;
+const BACK: usize = 1;
+
+enum TitlePage {
+    Main,
+    Options
+}
+
+enum TitleAction {
+    NewGame,
+    Continue,
+    HighScores,
+    Quit
+}
+
+pub struct TitleScreen {
+    main_menu: Menu,
+    options_menu: Menu,
+    page: TitlePage,
+    action: Option<TitleAction>,
+    text_writer: TextWriter,
+    game_data: Option<GameData>
+}
+
+impl TitleScreen {
+    pub fn new(game_data: GameData) -> TitleScreen {
+        let mut main_menu = Menu::new(&["New Game", "Continue", "High Scores", "Options", "Quit"]);
+        main_menu.set_enabled(CONTINUE, game_data.progress.checkpoint.is_some());
+
+        let mut options_menu = Menu::new(&["", "Back"]);
+        options_menu.set_label(SHOW_FPS, &fps_label(game_data.show_fps));
+
+        TitleScreen {
+            main_menu,
+            options_menu,
+            page: TitlePage::Main,
+            action: None,
+            text_writer: TextWriter::new(),
+            game_data: Some(game_data)
+        }
+    }
+
+    pub fn startup() -> TitleScreen {
+        TitleScreen::new(GameData::load())
+    }
+
+    fn select_main(&mut self) {
+        match self.main_menu.selected() {
+            NEW_GAME => self.action = Some(TitleAction::NewGame),
+            CONTINUE => self.action = Some(TitleAction::Continue),
+            HIGH_SCORES => self.action = Some(TitleAction::HighScores),
+            OPTIONS => self.page = TitlePage::Options,
+            QUIT => self.action = Some(TitleAction::Quit),
+            _ => {}
+        }
+    }
+
+    fn select_option(&mut self) {
+        match self.options_menu.selected() {
+            SHOW_FPS => {
+                if let Some(data) = self.game_data.as_mut() {
+                    data.show_fps = !data.show_fps;
+                    self.options_menu.set_label(SHOW_FPS, &fps_label(data.show_fps));
+                }
+            }
+            BACK => self.page = TitlePage::Main,
+            _ => {}
+        }
+    }
+}
+
+impl GameState for TitleScreen {
+    fn render(&mut self, ctx: &Context, gl: &mut GlGraphics, glyphs: &mut GlyphCache) {
+        let center_x = config::BOARD_SIZE_X as f64 / 2.0;
+
+        self.text_writer.render_text(
+            ctx,
+            gl,
+            glyphs,
+            colors::BLACK,
+            config::TITLE_FONT_SIZE,
+            center_x - config::TITLE_FONT_SIZE as f64 * 4.0,
+            config::BOARD_SIZE_Y as f64 / 3.0,
+            "Rusty Platformer");
+
+        let menu = match self.page {
+            TitlePage::Main => &mut self.main_menu,
+            TitlePage::Options => &mut self.options_menu
+        };
+
+        menu.render(ctx, gl, glyphs, center_x - config::MENU_FONT_SIZE as f64 * 3.0, config::BOARD_SIZE_Y as f64 / 2.0);
+    }
+
+    fn update(&mut self, _args: &UpdateArgs) -> State<GameData> {
+        match self.action.take() {
+            Some(TitleAction::NewGame) => {
+                let mut data = self.game_data.take().unwrap();
+                data.reset_run();
+                data.progress.checkpoint = None;
+                data.save();
+                State::Loading(data)
+            },
+            Some(TitleAction::Continue) => State::Loading(self.game_data.take().unwrap()),
+            Some(TitleAction::HighScores) => State::Leaderboard(self.game_data.take().unwrap()),
+            Some(TitleAction::Quit) => State::Quit,
+            None => State::None
+        }
+    }
+
+    fn key_press(&mut self, args: &Button) {
+        match self.page {
+            TitlePage::Main => {
+                if let MenuInput::Select = self.main_menu.handle_key(args) {
+                    self.select_main();
+                }
+            }
+            TitlePage::Options => {
+                match self.options_menu.handle_key(args) {
+                    MenuInput::Select => self.select_option(),
+                    MenuInput::Back => self.page = TitlePage::Main,
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn key_release(&mut self, _args: &Button) {}
+}
+
+fn fps_label(show_fps: bool) -> String {
+    if show_fps {
+        "Show FPS: On".to_string()
+    } else {
+        "Show FPS: Off".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::gamedata::LevelResult;
+
+    #[test]
+    fn new_game_starts_a_fresh_run() {
+        let mut data = GameData::new();
+        data.persist = false;
+        data.score = 800;
+        data.lives = 1;
+        data.last_result = Some(LevelResult { level: "level.map".to_string(), score: 800, time: 12.0 });
+
+        let mut title = TitleScreen::new(data);
+        title.action = Some(TitleAction::NewGame);
+
+        match title.update(&UpdateArgs { dt: 0.0 }) {
+            State::Loading(data) => {
+                assert_eq!(data.score, 0);
+                assert_eq!(data.lives, config::START_LIVES);
+                assert!(data.last_result.is_none());
+                assert!(data.progress.checkpoint.is_none());
+            },
+            _ => panic!("new game should start loading the level")
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use super::map_loader::LevelObject;
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum TriggerAction {
+    Checkpoint,
+    Exit
+}
+
+pub struct Trigger {
+    pub area: [f64; 4],
+    pub action: TriggerAction
+}
+
+impl Trigger {
+    pub fn new(area: [f64; 4], action: TriggerAction) -> Trigger {
+        Trigger {
+            area,
+            action
+        }
+    }
+
+    pub fn from_object(object: &LevelObject) -> Option<Trigger> {
+        let action = match object.properties.get("action").map(|action| action.as_str()) {
+            Some("checkpoint") => TriggerAction::Checkpoint,
+            Some("exit") => TriggerAction::Exit,
+            _ => {
+                eprintln!("ignoring trigger {} without a known action", object.name);
+                return None;
+            }
+        };
+
+        Some(Trigger::new([object.position[0], object.position[1], object.size[0], object.size[1]], action))
+    }
+
+    pub fn overlaps(&self, area: [f64; 4]) -> bool {
+        self.area[0] < area[0] + area[2]
+            && area[0] < self.area[0] + self.area[2]
+            && self.area[1] < area[1] + area[3]
+            && area[1] < self.area[1] + self.area[3]
+    }
+}